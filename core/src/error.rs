@@ -0,0 +1,117 @@
+use core::ops::{Add, Sub};
+
+/// Kind of the `Error`, which can be used to match on the problem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Time string is empty
+    Empty,
+    /// Value is not a valid number, like `1..2`
+    InvalidNumber,
+    /// Postfix is unknown, like `1 dya`
+    UnknownUnit,
+    /// Value has no postfix in the compound time string, like `1h 30`
+    MissingUnit,
+    /// Postfix is used twice in the compound time string, like `1h 2h`
+    RepeatedUnit,
+    /// Sign is used not at the start of the time string, like `1h -30m`
+    UnexpectedSign,
+    /// Time string has the wrong structure, like ISO 8601 duration without `P`
+    InvalidSyntax,
+    /// Value doesn't fit into the output type
+    Overflow,
+    /// Negative value can't be converted into `time.Duration`
+    NegativeDuration,
+    /// `UnitRegistry` has more different units than one time string can have
+    TooManyUnits,
+    /// Writing of the formatted time is failed
+    Format,
+    /// Error created by `Error::new` with a custom message
+    Other,
+}
+
+impl ErrorKind {
+    /// Human-readable description of the problem
+    pub const fn message(self) -> &'static str {
+        match self {
+            ErrorKind::Empty => "empty time string",
+            ErrorKind::InvalidNumber => "invalid value",
+            ErrorKind::UnknownUnit => "invalid postfix",
+            ErrorKind::MissingUnit => "missing postfix",
+            ErrorKind::RepeatedUnit => "repeated postfix",
+            ErrorKind::UnexpectedSign => "sign is allowed only at the start",
+            ErrorKind::InvalidSyntax => "invalid syntax",
+            ErrorKind::Overflow => "value is out of range",
+            ErrorKind::NegativeDuration => "time.Duration cannot work with negative values",
+            ErrorKind::TooManyUnits => "too many units",
+            ErrorKind::Format => "formatting error",
+            ErrorKind::Other => "error",
+        }
+    }
+}
+
+/// Error of the grammar with the byte span of the bad token in the time string
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Error {
+    pub kind: ErrorKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Error {
+    pub const fn new(kind: ErrorKind, start: usize, end: usize) -> Error {
+        Error { kind, start, end }
+    }
+}
+
+/// Finds the closest postfix for the unknown one by the optimal string alignment distance.
+/// Only the postfixes with a small distance are suggested.
+pub fn closest_postfix<I>(postfix: &[u8], postfixes: I) -> Option<&'static str>
+where
+    I: IntoIterator<Item = &'static str>,
+{
+    postfixes
+        .into_iter()
+        .filter_map(|candidate| {
+            let distance = get_distance(postfix, candidate.as_bytes())?;
+            Some((distance, candidate))
+        })
+        .filter(|(distance, _)| *distance <= 2 && *distance < postfix.len())
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+#[inline(always)]
+#[doc(hidden)]
+fn get_distance(a: &[u8], b: &[u8]) -> Option<usize> {
+    const MAX_LEN: usize = 32;
+    if a.len() >= MAX_LEN || b.len() >= MAX_LEN {
+        return None;
+    }
+
+    // Rows of the distance matrix: the row before previous, the previous one and the current one.
+    let mut rows = [[0_usize; MAX_LEN]; 3];
+    for (j, cell) in rows[1].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        rows[2][0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(!a[i.sub(1)].eq_ignore_ascii_case(&b[j.sub(1)]));
+            let mut distance = rows[1][j]
+                .add(1)
+                .min(rows[2][j.sub(1)].add(1))
+                .min(rows[1][j.sub(1)].add(cost));
+            if i > 1
+                && j > 1
+                && a[i.sub(1)].eq_ignore_ascii_case(&b[j.sub(2)])
+                && a[i.sub(2)].eq_ignore_ascii_case(&b[j.sub(1)])
+            {
+                distance = distance.min(rows[0][j.sub(2)].add(1));
+            }
+            rows[2][j] = distance;
+        }
+        rows.rotate_left(1);
+    }
+    Some(rows[1][b.len()])
+}
@@ -0,0 +1,318 @@
+/*!
+The grammar of human-like time strings, which is shared by `ms-converter` and `ms-converter-macros`.
+It's an implementation detail of `ms-converter`, use its functions instead.
+
+The grammar is written with `const fn` and the byte indices, so the same rules are used
+by `ms` function in the runtime, by `const_ms` in the const context and by the procedural macros.
+The time string is an optional sign and the segments of the value and the postfix, like `-1h 30m`:
+```
+use ms_converter_core::{next, segment, start, tail};
+
+let s = b"-1h 30m";
+let (negative, cursor) = start(s, false).unwrap();
+let part = segment(s, cursor).unwrap();
+assert!(negative);
+assert_eq!((part.value, part.postfix), ((1, 2), (2, 3)));
+
+let cursor = next(s, cursor, tail(s, cursor, part.postfix).unwrap()).unwrap().unwrap();
+let part = segment(s, cursor).unwrap();
+assert_eq!((part.value, part.postfix), ((4, 6), (6, 7)))
+```
+*/
+#![no_std]
+
+pub use error::{closest_postfix, Error, ErrorKind};
+pub use units::{postfix_nanos, postfixes, unit_index, UNITS};
+
+mod error;
+mod units;
+
+/// Returns the error from `const fn`, where `?` can't be used
+macro_rules! tri {
+    ($result:expr) => {
+        match $result {
+            Ok(value) => value,
+            Err(error) => return Err(error),
+        }
+    };
+}
+
+/// Position of the next segment in the time string
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor {
+    position: usize,
+    end: usize,
+    lenient: bool,
+    first: bool,
+}
+
+/// Byte spans of the value and the postfix of one segment, like `1.5` and `hours` in `1.5 hours`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    pub value: (usize, usize),
+    pub postfix: (usize, usize),
+}
+
+/// Skips the sign of the time string and returns `true` if it's negative with the cursor of the first segment.
+/// The lenient mode ignores the whitespaces around the sign and at the end of the time string.
+#[inline]
+pub const fn start(s: &[u8], lenient: bool) -> Result<(bool, Cursor), Error> {
+    let mut position = skip_whitespaces(s, 0, s.len(), lenient);
+    let negative = position < s.len() && s[position] == b'-';
+    if position < s.len() && matches!(s[position], b'+' | b'-') {
+        position = skip_whitespaces(s, position + 1, s.len(), lenient);
+    }
+    let mut end = s.len();
+    while lenient && end > position && s[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    if position == end {
+        return Err(Error::new(ErrorKind::Empty, 0, s.len()));
+    }
+
+    let cursor = Cursor {
+        position,
+        end,
+        lenient,
+        first: true,
+    };
+    Ok((negative, cursor))
+}
+
+/// Splits the value and the first word of the postfix at the cursor
+#[inline]
+pub const fn segment(s: &[u8], cursor: Cursor) -> Result<Segment, Error> {
+    let Cursor {
+        position,
+        end,
+        lenient,
+        first,
+    } = cursor;
+    if !first && matches!(s[position], b'+' | b'-') {
+        return Err(Error::new(
+            ErrorKind::UnexpectedSign,
+            position,
+            position + 1,
+        ));
+    }
+
+    let mut i = position;
+    let mut has_digit = false;
+    while i < end && (s[i].is_ascii_digit() || s[i] == b'.') {
+        has_digit = has_digit || s[i].is_ascii_digit();
+        i += 1;
+    }
+    if !has_digit {
+        return Err(Error::new(ErrorKind::InvalidNumber, position, i));
+    }
+    let value = (position, i);
+
+    if i < end && s[i].is_ascii_whitespace() {
+        i = skip_whitespaces(s, i + 1, end, lenient);
+    }
+    let postfix_start = i;
+    while i < end && !is_separator(s[i]) {
+        i += 1;
+    }
+    Ok(Segment {
+        value,
+        postfix: (postfix_start, i),
+    })
+}
+
+/// Checks the rest of the segment after the postfix, which can be longer than its first word,
+/// like the two words unit names of the locales. Returns the position of the tail.
+#[inline]
+pub const fn tail(s: &[u8], cursor: Cursor, postfix: (usize, usize)) -> Result<usize, Error> {
+    let tail = skip_whitespaces(s, postfix.1, cursor.end, cursor.lenient);
+    if tail < cursor.end && matches!(s[tail], b'+' | b'-') {
+        return Err(Error::new(ErrorKind::UnexpectedSign, tail, tail + 1));
+    }
+    if postfix.0 == postfix.1 && (!cursor.first || tail < cursor.end) {
+        return Err(Error::new(ErrorKind::MissingUnit, postfix.0, postfix.1));
+    }
+    Ok(tail)
+}
+
+/// Moves the cursor to the segment after the tail, `None` is the end of the time string
+#[inline]
+pub const fn next(s: &[u8], cursor: Cursor, tail: usize) -> Result<Option<Cursor>, Error> {
+    if tail >= cursor.end {
+        return Ok(None);
+    }
+    let position = match s[tail].is_ascii_whitespace() {
+        true if tail + 1 == cursor.end => {
+            return Err(Error::new(ErrorKind::InvalidSyntax, tail, tail + 1))
+        }
+        true => tail + 1,
+        false => tail,
+    };
+    Ok(Some(Cursor {
+        position,
+        first: false,
+        ..cursor
+    }))
+}
+
+/// Parses the time string with the built-in units into the sign and the nanoseconds count,
+/// like `ns` function of `ms-converter` does. Digits after the last nanosecond are rounded half up.
+pub const fn parse(s: &[u8]) -> Result<(bool, u128), Error> {
+    let (negative, mut cursor) = tri!(start(s, false));
+    let mut seen = [0_u64; UNITS.len()];
+    let mut seen_len = 0;
+    let mut total = 0_u128;
+    loop {
+        let part = tri!(segment(s, cursor));
+        let ((value_start, value_end), (postfix_start, postfix_end)) = (part.value, part.postfix);
+        let tail = tri!(tail(s, cursor, part.postfix));
+
+        let nanos = match postfix_nanos(slice(s, postfix_start, postfix_end)) {
+            Some(nanos) => nanos,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::UnknownUnit,
+                    postfix_start,
+                    postfix_end,
+                ))
+            }
+        };
+        let mut i = 0;
+        while i < seen_len {
+            if seen[i] == nanos {
+                return Err(Error::new(
+                    ErrorKind::RepeatedUnit,
+                    postfix_start,
+                    postfix_end,
+                ));
+            }
+            i += 1;
+        }
+        seen[seen_len] = nanos;
+        seen_len += 1;
+
+        total = match parse_nanos(slice(s, value_start, value_end), nanos) {
+            Ok(nanos) => match total.checked_add(nanos) {
+                Some(total) => total,
+                None => return Err(Error::new(ErrorKind::Overflow, value_start, postfix_end)),
+            },
+            Err(kind) => return Err(Error::new(kind, value_start, postfix_end)),
+        };
+
+        cursor = match tri!(next(s, cursor, tail)) {
+            Some(cursor) => cursor,
+            None => return Ok((negative, total)),
+        };
+    }
+}
+
+/// Multiplies the unsigned value by the nanoseconds count of the postfix
+/// without any floating point rounding. Digits after the last nanosecond are rounded half up.
+/// The fraction is separated by a dot or by a comma, like in ISO 8601 durations.
+#[inline]
+pub const fn parse_nanos(num: &[u8], nanos: u64) -> Result<u128, ErrorKind> {
+    let nanos = nanos as u128;
+    let mut i = 0;
+    while i < num.len() && !matches!(num[i], b'.' | b',') {
+        i += 1;
+    }
+    // 19 digits always fit into `u64`, which arithmetic is much faster than `u128` one,
+    // and the product of two `u64` values always fits into `u128`.
+    let integer = match i <= 19 {
+        true => {
+            let (mut j, mut integer) = (0, 0_u64);
+            while j < i {
+                integer = integer * 10 + (num[j] - b'0') as u64;
+                j += 1;
+            }
+            integer as u128 * nanos
+        }
+        false => {
+            let (mut j, mut integer) = (0, 0_u128);
+            while j < i {
+                integer = match integer.checked_mul(10) {
+                    Some(value) => match value.checked_add((num[j] - b'0') as u128) {
+                        Some(value) => value,
+                        None => return Err(ErrorKind::Overflow),
+                    },
+                    None => return Err(ErrorKind::Overflow),
+                };
+                j += 1;
+            }
+            match integer.checked_mul(nanos) {
+                Some(value) => value,
+                None => return Err(ErrorKind::Overflow),
+            }
+        }
+    };
+
+    // Every postfix is less than 2^64 nanoseconds,
+    // so the digits after the 20th change the value less than a nanosecond.
+    let (mut numerator, mut denominator, mut digits) = (0_u128, 1_u128, 0);
+    i += 1;
+    while i < num.len() {
+        if !num[i].is_ascii_digit() {
+            return Err(ErrorKind::InvalidNumber);
+        }
+        if digits < 20 {
+            numerator = numerator * 10 + (num[i] - b'0') as u128;
+            denominator *= 10;
+            digits += 1;
+        }
+        i += 1;
+    }
+    let fraction = mul_div_round(numerator, nanos, denominator);
+
+    match integer.checked_add(fraction) {
+        Some(value) => Ok(value),
+        None => Err(ErrorKind::Overflow),
+    }
+}
+
+/// Checks that the byte isn't a part of the postfix
+#[inline]
+pub const fn is_separator(b: u8) -> bool {
+    b.is_ascii_whitespace() || b.is_ascii_digit() || matches!(b, b'.' | b'+' | b'-')
+}
+
+/// Calculates `numerator * nanos / denominator` rounded half up,
+/// where the numerator is less than the denominator, which is a power of ten up to 10^20.
+/// The product of 20 digits and 2^64 nanoseconds doesn't fit into `u128`,
+/// so the numerator is split into two parts of 10 digits.
+#[inline(always)]
+#[doc(hidden)]
+const fn mul_div_round(numerator: u128, nanos: u128, denominator: u128) -> u128 {
+    const SPLIT: u128 = 10_000_000_000;
+    // The division of `u128` is much slower, so the small values are divided in `u64`.
+    if numerator == 0 {
+        return 0;
+    }
+    if denominator <= SPLIT && nanos <= u64::MAX as u128 {
+        if let Some(value) = (numerator as u64).checked_mul(nanos as u64) {
+            if let Some(value) = value.checked_add(denominator as u64 / 2) {
+                return (value / denominator as u64) as u128;
+            }
+        }
+    }
+    if denominator <= SPLIT {
+        return (numerator * nanos + denominator / 2) / denominator;
+    }
+    let (high, low) = (numerator / SPLIT, numerator % SPLIT);
+    let low = (low * nanos + denominator / 2) / SPLIT;
+    (high * nanos + low) / (denominator / SPLIT)
+}
+
+/// Skips the leading whitespaces in the lenient mode
+#[inline(always)]
+#[doc(hidden)]
+const fn skip_whitespaces(s: &[u8], mut i: usize, end: usize, lenient: bool) -> usize {
+    while lenient && i < end && s[i].is_ascii_whitespace() {
+        i += 1;
+    }
+    i
+}
+
+#[inline(always)]
+#[doc(hidden)]
+const fn slice(s: &[u8], start: usize, end: usize) -> &[u8] {
+    s.split_at(end).0.split_at(start).1
+}
@@ -0,0 +1,82 @@
+/// Postfixes of the built-in units with their nanoseconds count, the empty postfix is a millisecond.
+/// The calendar units go last, so the suggestions in errors prefer the units of `ms` from JavaScript.
+pub const UNITS: &[(&[&str], u64)] = &[
+    (&["years", "year", "yrs", "yr", "y"], 31_557_600_000_000_000),
+    (&["weeks", "week", "w"], 604_800_000_000_000),
+    (&["days", "day", "d"], 86_400_000_000_000),
+    (&["hours", "hour", "hrs", "hr", "h"], 3_600_000_000_000),
+    (&["minutes", "minute", "mins", "min", "m"], 60_000_000_000),
+    (&["seconds", "second", "secs", "sec", "s"], 1_000_000_000),
+    (
+        &["milliseconds", "millisecond", "msecs", "msec", "ms", ""],
+        1_000_000,
+    ),
+    // "µs" with the micro sign and "μs" with the greek mu
+    (
+        &["microseconds", "microsecond", "micros", "us", "µs", "μs"],
+        1_000,
+    ),
+    (&["nanoseconds", "nanosecond", "nanos", "ns"], 1),
+    // "mo" and not "m", which is a minute
+    (&["months", "month", "mo"], 2_629_800_000_000_000),
+    (&["quarters", "quarter", "q"], 7_889_400_000_000_000),
+    (&["decades", "decade"], 315_576_000_000_000_000),
+    (&["centuries", "century"], 3_155_760_000_000_000_000),
+];
+
+/// Finds the row of the built-in unit in `UNITS` by its postfix
+#[inline]
+pub const fn unit_index(postfix: &[u8]) -> Option<usize> {
+    match postfix.first() {
+        Some(b'y') if matches!(postfix, b"years" | b"year" | b"yrs" | b"yr" | b"y") => Some(0),
+        Some(b'w') if matches!(postfix, b"weeks" | b"week" | b"w") => Some(1),
+        Some(b'd') if matches!(postfix, b"days" | b"day" | b"d") => Some(2),
+        Some(b'd') if matches!(postfix, b"decades" | b"decade") => Some(11),
+        Some(b'h') if matches!(postfix, b"hours" | b"hour" | b"hrs" | b"hr" | b"h") => Some(3),
+        Some(b'm') if matches!(postfix, b"minutes" | b"minute" | b"mins" | b"min" | b"m") => {
+            Some(4)
+        }
+        None | Some(b'm')
+            if matches!(
+                postfix,
+                b"milliseconds" | b"millisecond" | b"msecs" | b"msec" | b"ms" | b""
+            ) =>
+        {
+            Some(6)
+        }
+        Some(b'm') if matches!(postfix, b"microseconds" | b"microsecond" | b"micros") => Some(7),
+        // "mo" and not "m", which is a minute
+        Some(b'm') if matches!(postfix, b"months" | b"month" | b"mo") => Some(9),
+        Some(b's') if matches!(postfix, b"seconds" | b"second" | b"secs" | b"sec" | b"s") => {
+            Some(5)
+        }
+        // "µs" with the micro sign and "μs" with the greek mu
+        Some(b'u' | 0xC2 | 0xCE) if matches!(postfix, b"us" | b"\xC2\xB5s" | b"\xCE\xBCs") => {
+            Some(7)
+        }
+        Some(b'n') if matches!(postfix, b"nanoseconds" | b"nanosecond" | b"nanos" | b"ns") => {
+            Some(8)
+        }
+        Some(b'q') if matches!(postfix, b"quarters" | b"quarter" | b"q") => Some(10),
+        Some(b'c') if matches!(postfix, b"centuries" | b"century") => Some(12),
+        _ => None,
+    }
+}
+
+/// Finds the nanoseconds count of the built-in unit by its postfix
+#[inline]
+pub const fn postfix_nanos(postfix: &[u8]) -> Option<u64> {
+    match unit_index(postfix) {
+        Some(index) => Some(UNITS[index].1),
+        None => None,
+    }
+}
+
+/// Every postfix of the built-in units, which is suggested in errors,
+/// it's all of them except the empty postfix and "μs" with the greek mu
+pub fn postfixes() -> impl Iterator<Item = &'static str> {
+    UNITS
+        .iter()
+        .flat_map(|(postfixes, _)| postfixes.iter().copied())
+        .filter(|postfix| !matches!(*postfix, "" | "\u{3bc}s"))
+}
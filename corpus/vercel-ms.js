@@ -0,0 +1,116 @@
+// Generates vercel-ms.json, the corpus of `ms_converter::compat::vercel` tests.
+//
+// npm install ms@2.1.3
+// node corpus/vercel-ms.js > corpus/vercel-ms.json
+
+const ms = require('ms');
+
+// String(-0) is "0", so the sign of zero is kept explicitly.
+const number = (value) => (Object.is(value, -0) ? '-0' : String(value));
+
+const call = (input, options) => {
+  try {
+    const output = ms(input, options);
+    return output === undefined ? null : output;
+  } catch (e) {
+    return { error: true };
+  }
+};
+
+const parse = [];
+const seen = new Set();
+const addParse = (input) => {
+  if (seen.has(input)) {
+    return;
+  }
+  seen.add(input);
+  const output = call(input);
+  parse.push({
+    input,
+    output: typeof output === 'number' ? number(output) : output,
+  });
+};
+
+const values = [
+  '0', '-0', '1', '-1', '00', '007', '1.5', '-1.5', '.5', '-.5', '0.1', '1.', '.', '-', '-.',
+  '+1', '--1', '1e3', '1.2.3', '0.0000001', '123456789.987654321', '9007199254740993',
+  '1' + '0'.repeat(30), '0.' + '3'.repeat(40),
+];
+const postfixes = [
+  '', 'ms', 'msec', 'msecs', 'millisecond', 'milliseconds',
+  's', 'sec', 'secs', 'second', 'seconds',
+  'm', 'min', 'mins', 'minute', 'minutes',
+  'h', 'hr', 'hrs', 'hour', 'hours',
+  'd', 'day', 'days', 'w', 'week', 'weeks',
+  'y', 'yr', 'yrs', 'year', 'years',
+  'us', 'ns', 'µs', 'mo', 'dy', 'sss', 'msecss',
+];
+const cases = [(p) => p, (p) => p.toUpperCase(), (p) => p.charAt(0).toUpperCase() + p.slice(1)];
+
+for (const value of values) {
+  for (const postfix of postfixes) {
+    for (const separator of ['', ' ', '  ']) {
+      addParse(value + separator + postfix);
+    }
+  }
+}
+for (const value of ['1', '-1.5', '.5']) {
+  for (const postfix of postfixes) {
+    for (const separator of ['', ' ', '\t']) {
+      for (const apply of cases) {
+        addParse(value + separator + apply(postfix));
+      }
+    }
+  }
+}
+
+[
+  '', ' ', '1h 30m', '1h30m', ' 1h', '1h ', '1 ', '1  ', '1\n', '1 h\n', '1_000', '1,5h', '١h',
+  '1ſ', '1K', '1 hours ago', 'h', '1' + ' '.repeat(99), '1' + ' '.repeat(98) + 'h',
+  '1' + ' '.repeat(98) + 'ms', '1' + '0'.repeat(99), '1' + '0'.repeat(100),
+  '1' + ' '.repeat(97) + 'µ', '1' + ' '.repeat(98) + '😀',
+].forEach(addParse);
+
+const format = [];
+const addFormat = (input) => {
+  for (const long of [false, true]) {
+    const output = call(input, { long });
+    format.push({ input: number(input), long, output });
+  }
+};
+
+const s = 1000;
+const m = s * 60;
+const h = m * 60;
+const d = h * 24;
+for (const period of [1, s, m, h, d, 7 * d, 365.25 * d]) {
+  for (const factor of [0, 0.1, 0.5, 0.999, 1, 1.001, 1.4999, 1.5, 1.5001, 2, 2.5, 3.5, 59.5, 1000]) {
+    addFormat(period * factor);
+    addFormat(-period * factor);
+  }
+}
+[
+  -0, 0.5, -0.5, 1e-7, 1e-6, 123.456, 999, 999.5, 999.9999, 1499, 1500, -1500, 89999, 90000,
+  1e21, 1e21 * d, -1e21 * d, 2 ** 53, 2 ** 53 * d, 1e300, -1e300, Number.MAX_VALUE,
+  Number.MIN_VALUE, Number.EPSILON, NaN, Infinity, -Infinity,
+].forEach(addFormat);
+
+// Deterministic pseudo-random values of every magnitude.
+let seed = 42;
+const random = () => {
+  seed = (seed * 1103515245 + 12345) % 2147483648;
+  return seed / 2147483648;
+};
+for (let i = 0; i < 300; i++) {
+  const value = (random() - 0.5) * 10 ** Math.floor(random() * 40 - 10);
+  addFormat(value);
+  addFormat(Math.round(value));
+}
+
+process.stdout.write(
+  '{\n  "parse": [\n' +
+    parse.map((c) => '    ' + JSON.stringify(c)).join(',\n') +
+    '\n  ],\n  "format": [\n' +
+    format.map((c) => '    ' + JSON.stringify(c)).join(',\n') +
+    '\n  ]\n}\n'
+);
@@ -0,0 +1,193 @@
+/*!
+Procedural macros of `ms-converter`, which convert human-like time strings into constants
+in the compilation time. Enable `macros` feature of `ms-converter` to use them.
+
+The time string follows the same grammar as `ms` function, and the invalid time string
+is a compile error, which points at the literal:
+```compile_fail
+use ms_converter::ms;
+
+let value: i64 = ms!("1 dya"); // error: invalid postfix at 2..5, did you mean `day`?
+```
+
+Values are calculated in the integer nanoseconds,
+`ms!` and `ms_i64!` round them half away from zero into milliseconds like `const_ms` does.
+*/
+
+extern crate proc_macro;
+
+use ms_converter_core::{closest_postfix, postfixes, ErrorKind};
+use proc_macro::TokenStream;
+use proc_macro2::{Literal, Span};
+use quote::{quote, quote_spanned};
+use std::convert::TryFrom;
+use syn::{parse_macro_input, LitStr};
+
+#[cfg(test)]
+mod tests;
+
+/// Converting human-like times into milliseconds in the compilation time.
+/// `ms!` macro gets a string literal and expands into the integer literal,
+/// so it can be used with any integer type, which fits the value.
+///
+/// ### Usage
+/// ```
+/// use ms_converter::ms;
+///
+/// const TIMEOUT: u32 = ms!("1h 30m");
+/// assert_eq!(TIMEOUT, 5400000)
+/// ```
+///
+/// also you can a pass negative values
+/// ```
+/// use ms_converter::ms;
+///
+/// const VALUE: i64 = ms!("-2 days");
+/// assert_eq!(VALUE, -172800000)
+/// ```
+///
+/// The value, which doesn't fit into the type, is a compile error:
+/// ```compile_fail
+/// use ms_converter::ms;
+///
+/// const VALUE: i32 = ms!("25 days");
+/// ```
+#[proc_macro]
+pub fn ms(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as LitStr);
+    match get_milliseconds(&lit) {
+        Ok(milliseconds) => {
+            let mut value = Literal::u64_unsuffixed(milliseconds.unsigned_abs());
+            value.set_span(lit.span());
+            match milliseconds < 0 {
+                true => quote_spanned!(lit.span()=> -#value),
+                false => quote!(#value),
+            }
+        }
+        Err(error) => error.to_compile_error(),
+    }
+    .into()
+}
+
+/// Converting human-like times into milliseconds in the compilation time.
+/// `ms_i64!` macro gets a string literal and expands into `i64` constant, like `ms` function returns.
+///
+/// ### Usage
+/// ```
+/// use ms_converter::ms_i64;
+///
+/// let value = ms_i64!("1d");
+/// assert_eq!(value, 86400000_i64)
+/// ```
+///
+/// also you can a pass negative values
+/// ```
+/// use ms_converter::ms_i64;
+///
+/// let value = ms_i64!("-1.5h");
+/// assert_eq!(value, -5400000)
+/// ```
+#[proc_macro]
+pub fn ms_i64(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as LitStr);
+    match get_milliseconds(&lit) {
+        Ok(milliseconds) => {
+            let mut value = Literal::i64_suffixed(milliseconds);
+            value.set_span(lit.span());
+            quote!(#value)
+        }
+        Err(error) => error.to_compile_error(),
+    }
+    .into()
+}
+
+/// Converting human-like times into `time.Duration` in the compilation time.
+/// `duration!` macro gets a string literal and expands into `time.Duration` constant
+/// with the nanoseconds precision, like `ns_into_time` function returns.
+///
+/// ### Usage
+/// ```
+/// use core::time::Duration;
+/// use ms_converter::duration;
+///
+/// const TIMEOUT: Duration = duration!("1h 30m");
+/// assert_eq!(TIMEOUT, Duration::from_secs(5400));
+///
+/// const DELAY: Duration = duration!("1.0005s");
+/// assert_eq!(DELAY.as_nanos(), 1000500000)
+/// ```
+///
+/// `time.Duration` can't be negative, so the negative value is a compile error:
+/// ```compile_fail
+/// use ms_converter::duration;
+///
+/// let value = duration!("-1d");
+/// ```
+#[proc_macro]
+pub fn duration(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as LitStr);
+    let nanoseconds = match parse(&lit.value()) {
+        Ok((true, nanoseconds)) if nanoseconds > 0 => Err(syn::Error::new(
+            lit.span(),
+            ErrorKind::NegativeDuration.message(),
+        )),
+        Ok((_, nanoseconds)) => u64::try_from(nanoseconds / 1_000_000_000)
+            .map(|secs| (secs, (nanoseconds % 1_000_000_000) as u32))
+            .map_err(|_| syn::Error::new(lit.span(), ErrorKind::Overflow.message())),
+        Err(error) => Err(syn::Error::new(lit.span(), error)),
+    };
+    match nanoseconds {
+        Ok((secs, nanos)) => {
+            let (secs, nanos) = (
+                get_literal(secs, lit.span()),
+                get_literal(nanos, lit.span()),
+            );
+            quote_spanned!(lit.span()=> ::core::time::Duration::new(#secs, #nanos))
+        }
+        Err(error) => error.to_compile_error(),
+    }
+    .into()
+}
+
+/// Parses the literal and rounds the nanoseconds into milliseconds, which fit into `i64`
+#[inline(always)]
+#[doc(hidden)]
+fn get_milliseconds(lit: &LitStr) -> syn::Result<i64> {
+    let value = lit.value();
+    let (negative, nanoseconds) =
+        parse(&value).map_err(|error| syn::Error::new(lit.span(), error))?;
+    let milliseconds = (nanoseconds + 500_000) / 1_000_000;
+    match negative {
+        true if milliseconds <= 1 << 63 => Ok((milliseconds as i64).wrapping_neg()),
+        false if milliseconds < 1 << 63 => Ok(milliseconds as i64),
+        _ => Err(syn::Error::new(
+            lit.span(),
+            format!("{} at 0..{}", ErrorKind::Overflow.message(), value.len()),
+        )),
+    }
+}
+
+/// Parses the time string by the grammar of `ms` function into the sign and the nanoseconds count,
+/// the error has the same message as `ms_converter::Error` has
+#[inline(always)]
+#[doc(hidden)]
+fn parse(s: &str) -> Result<(bool, u128), String> {
+    ms_converter_core::parse(s.as_bytes()).map_err(|error| {
+        let mut message = format!("{} at {}..{}", error.kind.message(), error.start, error.end);
+        if error.kind == ErrorKind::UnknownUnit {
+            let postfix = &s.as_bytes()[error.start..error.end];
+            if let Some(suggestion) = closest_postfix(postfix, postfixes()) {
+                message.push_str(&format!(", did you mean `{}`?", suggestion));
+            }
+        }
+        message
+    })
+}
+
+#[inline(always)]
+#[doc(hidden)]
+fn get_literal<T: Into<u64>>(value: T, span: Span) -> Literal {
+    let mut literal = Literal::u64_unsuffixed(value.into());
+    literal.set_span(span);
+    literal
+}
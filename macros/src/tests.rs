@@ -0,0 +1,61 @@
+use crate::parse;
+
+#[test]
+fn parse_same_as_ns() {
+    let values = [
+        "1d",
+        "1.5h",
+        "100",
+        "-3 days",
+        "+1 week",
+        "2 years",
+        "1h 30m",
+        "2d4h15m",
+        "2 days 4 hours",
+        "0.1s",
+        "1.s",
+        "1ms 500ns",
+        "1μs",
+        "1 y 1 w 1 d 1 h 1 m 1 s 1 ms",
+        "6 months",
+        "1 q",
+        "1 decade 2 mo",
+        "0.5 century",
+    ];
+    for value in values {
+        let (negative, nanoseconds) = parse(value).unwrap();
+        let nanoseconds = nanoseconds as i64;
+        let nanoseconds = if negative { -nanoseconds } else { nanoseconds };
+        assert_eq!(nanoseconds, ms_converter::ns(value).unwrap())
+    }
+}
+
+#[test]
+fn parse_same_errors_as_ms() {
+    let values = [
+        "",
+        "-",
+        "h",
+        "1..2h",
+        "1 dya",
+        "1h 30 mnis",
+        "1 hoursss",
+        "1h 30",
+        "1h 2h",
+        "1ms 1",
+        "1h -30m",
+        "1h+30m",
+        "1h ",
+        "1 mo 1 month",
+        "1 quartr",
+        "1 xyzxyzxyzxyzxyzxyzxyzxyzxyzxyzxyz",
+    ];
+    for value in values {
+        assert_eq!(
+            parse(value).unwrap_err(),
+            ms_converter::ms(value).unwrap_err().to_string(),
+            "{}",
+            value
+        )
+    }
+}
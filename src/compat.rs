@@ -0,0 +1,7 @@
+/*!
+Compatibility with the other implementations of human-like times.
+
+* `vercel` parses and formats exactly like the JavaScript [`ms`](https://github.com/vercel/ms) package
+*/
+
+pub mod vercel;
@@ -0,0 +1,318 @@
+/*!
+Compatibility with the JavaScript [`ms`](https://github.com/vercel/ms) package version 2.1.3,
+so the services ported from Node.js read and write the same time strings.
+
+`parse` works like `ms(str)`, `format` and `format_long` work like `ms(n)` and `ms(n, { long: true })`.
+The rules are different from `ms` function of this crate:
+* Only one value is allowed, without `+` sign, microseconds and nanoseconds.
+* Postfixes are case-insensitive and any number of spaces is allowed before the postfix.
+* Values are not rounded, so the fractional milliseconds are kept.
+* Formatted values are rounded half up like `Math.round` and the long postfix is plural from `1.5` units,
+  so `ms(1500, { long: true })` is `2 seconds`, but `ms(1499, { long: true })` is `1 second`.
+
+Every rule is checked against the corpus of the time strings and their JavaScript results,
+which is generated by `corpus/vercel-ms.js`.
+
+```
+# #[cfg(feature = "alloc")] {
+use crate::ms_converter::compat::vercel;
+
+assert_eq!(vercel::parse("1.5 Hours").unwrap(), 5400000.);
+assert_eq!(vercel::format(-1500.).unwrap(), "-1s");
+assert_eq!(vercel::format_long(90000.).unwrap(), "2 minutes");
+# }
+```
+*/
+
+use crate::{Error, ErrorKind, DAY, HOUR, MINUTE, SECOND, WEEK, YEAR};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::{self, Display, Formatter, Write};
+use core::ops::{Add, Div, Mul, Sub};
+
+/// Maximum length of the time string in UTF-16 code units, the longer strings are not parsed
+const MAX_LEN: usize = 100;
+
+/// Converting human-like times into milliseconds like `ms(str)` in JavaScript.
+/// `parse` function gets an str slice or String and returns the milliseconds count,
+/// which can be fractional or negative zero as in JavaScript.
+/// The empty string is `ErrorKind::Empty`, where JavaScript throws,
+/// and the unsupported one is `ErrorKind::InvalidSyntax`, where JavaScript returns `undefined`.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::compat::vercel;
+///
+/// assert_eq!(vercel::parse("2 DAYS").unwrap(), 172800000.);
+/// assert_eq!(vercel::parse("1.5ms").unwrap(), 1.5);
+/// assert_eq!(vercel::parse("-.5h").unwrap(), -1800000.);
+/// assert!(vercel::parse("1h 30m").is_err());
+/// ```
+///
+/// ### Supported postfixes
+/// * **Years:** `years`, `year`, `yrs`, `yr`, `y`
+/// * **Weeks:** `weeks`, `week`, `w`
+/// * **Days:** `days`, `day`, `d`
+/// * **Hours:** `hours`, `hour`, `hrs`, `hr`, `h`
+/// * **Minutes:** `minutes`, `minute`, `mins`, `min`, `m`
+/// * **Seconds:** `seconds`, `second`, `secs`, `sec`, `s`
+/// * **Milliseconds:** `milliseconds`, `millisecond`, `msecs`, `msec`, `ms` and empty postfix
+pub fn parse<T>(s: T) -> Result<f64, Error>
+where
+    T: AsRef<str>,
+{
+    let s = s.as_ref();
+    if s.is_empty() {
+        return Err(ErrorKind::Empty.into());
+    }
+    let invalid = || Error::from(ErrorKind::InvalidSyntax).with_span(0, s.len());
+    if s.encode_utf16().nth(MAX_LEN).is_some() {
+        return Err(invalid());
+    }
+
+    // The same as `/^(-?(?:\d+)?\.?\d+) *(postfix)?$/i` regular expression.
+    let b = s.as_bytes();
+    let sign = usize::from(b[0] == b'-');
+    let integer = get_digits_len(&b[sign..]);
+    let mut end = sign.add(integer);
+    if b.get(end) == Some(&b'.') {
+        match get_digits_len(&b[end.add(1)..]) {
+            0 => return Err(invalid()),
+            fraction => end = end.add(1).add(fraction),
+        }
+    } else if integer == 0 {
+        return Err(invalid());
+    }
+    let postfix = s[end..].trim_start_matches(' ');
+
+    let modification = get_modification(postfix).ok_or_else(invalid)?;
+    let value: f64 = s[..end].parse().map_err(|_| invalid())?;
+    Ok(match modification {
+        Some(modification) => value.mul(modification),
+        None => value,
+    })
+}
+
+/// Getting human-like time from milliseconds like `ms(n)` in JavaScript.
+/// `format` function gets a milliseconds count and returns a string with the max possible period up to days,
+/// `NaN` and infinite values are `ErrorKind::InvalidNumber`, where JavaScript throws.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::compat::vercel;
+///
+/// assert_eq!(vercel::format(86400000.).unwrap(), "1d");
+/// assert_eq!(vercel::format(-2.5 * 86400000.).unwrap(), "-2d");
+/// assert_eq!(vercel::format(1.5).unwrap(), "1.5ms");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn format(milliseconds: f64) -> Result<String, Error> {
+    let mut s = String::new();
+    write(&mut s, milliseconds)?;
+    Ok(s)
+}
+
+/// Getting long human-like time from milliseconds like `ms(n, { long: true })` in JavaScript.
+/// `format_long` function gets a milliseconds count and returns a string with the max possible period up to days,
+/// `NaN` and infinite values are `ErrorKind::InvalidNumber`, where JavaScript throws.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::compat::vercel;
+///
+/// assert_eq!(vercel::format_long(86400000.).unwrap(), "1 day");
+/// assert_eq!(vercel::format_long(1.4 * 86400000.).unwrap(), "1 day");
+/// assert_eq!(vercel::format_long(1.5 * 86400000.).unwrap(), "2 days");
+/// assert_eq!(vercel::format_long(500.).unwrap(), "500 ms");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn format_long(milliseconds: f64) -> Result<String, Error> {
+    let mut s = String::new();
+    write_long(&mut s, milliseconds)?;
+    Ok(s)
+}
+
+/// Writes the milliseconds count like `format` function into `fmt::Write`.
+///
+/// ```
+/// use crate::ms_converter::compat::vercel;
+///
+/// let mut value = String::new();
+/// vercel::write(&mut value, 5400000.).unwrap();
+/// assert_eq!(value, "2h")
+/// ```
+pub fn write<W: Write>(w: &mut W, milliseconds: f64) -> Result<(), Error> {
+    let (value, postfix) = match get_period(milliseconds)? {
+        Some((period, postfix, _)) => (round(milliseconds.div(period)), postfix),
+        None => (milliseconds, "ms"),
+    };
+    write!(w, "{}{}", JsNumber(value), postfix).map_err(|_| ErrorKind::Format.into())
+}
+
+/// Writes the milliseconds count like `format_long` function into `fmt::Write`.
+///
+/// ```
+/// use crate::ms_converter::compat::vercel;
+///
+/// let mut value = String::new();
+/// vercel::write_long(&mut value, 5400000.).unwrap();
+/// assert_eq!(value, "2 hours")
+/// ```
+pub fn write_long<W: Write>(w: &mut W, milliseconds: f64) -> Result<(), Error> {
+    match get_period(milliseconds)? {
+        Some((period, _, name)) => {
+            let plural = match milliseconds.abs() >= period.mul(1.5) {
+                true => "s",
+                false => "",
+            };
+            let value = round(milliseconds.div(period));
+            write!(w, "{} {}{}", JsNumber(value), name, plural)
+        }
+        None => write!(w, "{} ms", JsNumber(milliseconds)),
+    }
+    .map_err(|_| ErrorKind::Format.into())
+}
+
+/// Finds the period, its short and long postfixes like `fmtShort` and `fmtLong` in JavaScript,
+/// values less than a second have no period
+#[inline(always)]
+#[doc(hidden)]
+fn get_period(milliseconds: f64) -> Result<Option<(f64, &'static str, &'static str)>, Error> {
+    if !milliseconds.is_finite() {
+        return Err(ErrorKind::InvalidNumber.into());
+    }
+    let abs = milliseconds.abs();
+    Ok([
+        (DAY, "d", "day"),
+        (HOUR, "h", "hour"),
+        (MINUTE, "m", "minute"),
+        (SECOND, "s", "second"),
+    ]
+    .iter()
+    .find(|(period, _, _)| abs >= *period)
+    .copied())
+}
+
+/// Returns the modification of the postfix, `None` is milliseconds without the multiplication
+#[inline(always)]
+#[doc(hidden)]
+fn get_modification(postfix: &str) -> Option<Option<f64>> {
+    let postfixes: [(&[&str], Option<f64>); 7] = [
+        (&["years", "year", "yrs", "yr", "y"], Some(YEAR)),
+        (&["weeks", "week", "w"], Some(WEEK)),
+        (&["days", "day", "d"], Some(DAY)),
+        (&["hours", "hour", "hrs", "hr", "h"], Some(HOUR)),
+        (&["minutes", "minute", "mins", "min", "m"], Some(MINUTE)),
+        (&["seconds", "second", "secs", "sec", "s"], Some(SECOND)),
+        (
+            &["milliseconds", "millisecond", "msecs", "msec", "ms", ""],
+            None,
+        ),
+    ];
+    postfixes
+        .iter()
+        .find(|(names, _)| names.iter().any(|name| name.eq_ignore_ascii_case(postfix)))
+        .map(|(_, modification)| *modification)
+}
+
+#[inline(always)]
+#[doc(hidden)]
+fn get_digits_len(s: &[u8]) -> usize {
+    s.iter().take_while(|b| b.is_ascii_digit()).count()
+}
+
+/// Rounds half up like `Math.round`, the values are at least one unit, so the subtraction is exact
+#[inline(always)]
+#[doc(hidden)]
+fn round(value: f64) -> f64 {
+    let rounded = crate::float::round(value);
+    match value < 0_f64 && rounded.sub(value) == -0.5 {
+        true => rounded.add(1_f64),
+        false => rounded,
+    }
+}
+
+/// Displays the number like `Number.prototype.toString` in JavaScript
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+struct JsNumber(f64);
+
+impl Display for JsNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let value = self.0;
+        if value == 0_f64 {
+            return f.write_char('0');
+        }
+        if value < 0_f64 {
+            f.write_char('-')?;
+        }
+
+        // The shortest digits, which are read back into the same number, like JavaScript uses.
+        let mut buffer = Buffer([0; 32], 0);
+        write!(buffer, "{:e}", value.abs())?;
+        let (mantissa, exponent) = buffer.as_str().split_once('e').ok_or(fmt::Error)?;
+        let exponent: i32 = exponent.parse().map_err(|_| fmt::Error)?;
+        let (first, rest) = mantissa.split_at(1);
+        let rest = rest.trim_start_matches('.');
+        let (digits, point) = (rest.len().add(1) as i32, exponent.add(1));
+
+        match point {
+            p if digits <= p && p <= 21 => {
+                write!(
+                    f,
+                    "{}{}{:0<width$}",
+                    first,
+                    rest,
+                    "",
+                    width = p.sub(digits) as usize
+                )
+            }
+            p if 0 < p && p <= 21 => {
+                let (integer, fraction) = rest.split_at(p.sub(1) as usize);
+                write!(f, "{}{}.{}", first, integer, fraction)
+            }
+            p if -6 < p && p <= 0 => write!(
+                f,
+                "0.{:0<width$}{}{}",
+                "",
+                first,
+                rest,
+                width = p.unsigned_abs() as usize
+            ),
+            p => {
+                let sign = match p > 0 {
+                    true => '+',
+                    false => '-',
+                };
+                match rest.is_empty() {
+                    true => write!(f, "{}e{}{}", first, sign, p.sub(1).unsigned_abs()),
+                    false => write!(f, "{}.{}e{}{}", first, rest, sign, p.sub(1).unsigned_abs()),
+                }
+            }
+        }
+    }
+}
+
+/// Fixed size buffer for the digits of `f64`
+#[doc(hidden)]
+struct Buffer([u8; 32], usize);
+
+impl Buffer {
+    #[inline(always)]
+    #[doc(hidden)]
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.0[..self.1]).unwrap_or_default()
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.1.add(s.len());
+        self.0
+            .get_mut(self.1..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.1 = end;
+        Ok(())
+    }
+}
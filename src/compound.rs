@@ -0,0 +1,306 @@
+use crate::{Error, ErrorKind, Locale, Rounding, Unit};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::{self, Write};
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// Getting compound human-like time from milliseconds.
+/// `get_compound_duration` function gets a milliseconds count and returns a compound string with your time,
+/// like `1d 1h 1m 1s`. Use `CompoundFormat` to configure the output.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::{get_compound_duration, ms};
+///
+/// let value = get_compound_duration(90061000).unwrap();
+/// assert_eq!(value, "1d 1h 1m 1s");
+/// assert_eq!(ms(value).unwrap(), 90061000)
+/// ```
+///
+/// also you can a pass negative values
+/// ```
+/// use crate::ms_converter::get_compound_duration;
+///
+/// let value = get_compound_duration(-5400500).unwrap();
+/// assert_eq!(value, "-1h 30m 500ms")
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn get_compound_duration(milliseconds: i64) -> Result<String, Error> {
+    CompoundFormat::new().format(milliseconds)
+}
+
+/// Options of the compound human-like time formatting.
+/// The output is always parsed back by `ms` function into the formatted value,
+/// the long names of other locales are parsed back by `Parser` with the same locale.
+/// The part of the time, which doesn't fit into the smallest unit or the maximum number of components,
+/// is rounded half away from zero or with your rounding mode.
+///
+/// ### Usage
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use crate::ms_converter::{CompoundFormat, Unit};
+///
+/// let format = CompoundFormat::new()
+///     .largest(Unit::Week)
+///     .smallest(Unit::Second)
+///     .max_components(2)
+///     .long(true);
+/// assert_eq!(format.format(1_000_000_000).unwrap(), "1 week 5 days");
+/// # }
+/// ```
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use crate::ms_converter::{ms, CompoundFormat};
+///
+/// let value = CompoundFormat::new().separator("").format(9000500).unwrap();
+/// assert_eq!(value, "2h30m500ms");
+/// assert_eq!(ms(value).unwrap(), 9000500)
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompoundFormat<'a> {
+    largest: Unit,
+    smallest: Unit,
+    max_components: usize,
+    separator: &'a str,
+    long: bool,
+    locale: Locale,
+    rounding: Rounding,
+}
+
+impl<'a> CompoundFormat<'a> {
+    /// Creates the default options: from days to milliseconds,
+    /// with unlimited components, separated by a whitespace and with short postfixes.
+    pub const fn new() -> CompoundFormat<'static> {
+        CompoundFormat {
+            largest: Unit::Day,
+            smallest: Unit::Millisecond,
+            max_components: usize::MAX,
+            separator: " ",
+            long: false,
+            locale: Locale::English,
+            rounding: Rounding::HalfAwayFromZero,
+        }
+    }
+
+    /// The largest unit of the output, the larger values are written in this unit
+    pub const fn largest(mut self, unit: Unit) -> Self {
+        self.largest = unit;
+        self
+    }
+
+    /// The smallest unit of the output, units less than a millisecond are treated as a millisecond,
+    /// months, quarters, decades and centuries are never written and treated as the smaller units
+    pub const fn smallest(mut self, unit: Unit) -> Self {
+        self.smallest = unit;
+        self
+    }
+
+    /// The maximum number of the written components, at least one component is always written
+    pub const fn max_components(mut self, max_components: usize) -> Self {
+        self.max_components = max_components;
+        self
+    }
+
+    /// The separator between the components, which is empty or a single whitespace like `ms` function accepts.
+    /// Other separators are `ErrorKind::InvalidSyntax` error of the formatting, the output can't be parsed back.
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use crate::ms_converter::{CompoundFormat, ErrorKind};
+    ///
+    /// assert_eq!(CompoundFormat::new().separator("\n").format(5_400_000).unwrap(), "1h\n30m");
+    /// let error = CompoundFormat::new().separator(", ").format(5_400_000).unwrap_err();
+    /// assert_eq!(error.kind(), ErrorKind::InvalidSyntax)
+    /// # }
+    /// ```
+    pub const fn separator<'b>(self, separator: &'b str) -> CompoundFormat<'b> {
+        CompoundFormat {
+            largest: self.largest,
+            smallest: self.smallest,
+            max_components: self.max_components,
+            separator,
+            long: self.long,
+            locale: self.locale,
+            rounding: self.rounding,
+        }
+    }
+
+    /// Uses the long postfixes, like `2 hours`, instead of the short ones, like `2h`
+    pub const fn long(mut self, long: bool) -> Self {
+        self.long = long;
+        self
+    }
+
+    /// The language of the long postfixes, like `1 день 2 часа` in Russian
+    ///
+    /// ```
+    /// # #[cfg(feature = "alloc")] {
+    /// use crate::ms_converter::{CompoundFormat, Locale};
+    ///
+    /// let format = CompoundFormat::new().long(true).locale(Locale::Russian);
+    /// assert_eq!(format.format(93_600_000).unwrap(), "1 день 2 часа")
+    /// # }
+    /// ```
+    pub const fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// The rounding mode of the part, which doesn't fit into the output
+    pub const fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Formats the milliseconds count with these options
+    #[cfg(feature = "alloc")]
+    pub fn format(&self, milliseconds: i64) -> Result<String, Error> {
+        let mut s = String::new();
+        self.write(&mut s, milliseconds)?;
+        Ok(s)
+    }
+
+    /// Writes the milliseconds count with these options into `fmt::Write` without allocation
+    ///
+    /// ```
+    /// use crate::ms_converter::CompoundFormat;
+    ///
+    /// let mut value = String::new();
+    /// CompoundFormat::new().write(&mut value, 5_400_000).unwrap();
+    /// assert_eq!(value, "1h 30m")
+    /// ```
+    pub fn write<W: Write>(&self, w: &mut W, milliseconds: i64) -> Result<(), Error> {
+        let separator = self.separator.as_bytes();
+        if separator.len() > 1 || !separator.iter().all(u8::is_ascii_whitespace) {
+            return Err(ErrorKind::InvalidSyntax.into());
+        }
+
+        let units = self.units();
+        let abs = u128::from(milliseconds.unsigned_abs());
+        let negative = milliseconds.is_negative();
+
+        // Every try keeps one more small unit, until the components don't fit into the limit.
+        let (last, (counts, rounded)) = (0..units.len())
+            .rev()
+            .map(|last| (last, split(abs, negative, &units[last..], self.rounding)))
+            .take_while(|(_, (counts, _))| {
+                counts.iter().filter(|c| **c > 0).count() <= self.max_components.max(1)
+            })
+            .last()
+            .unwrap_or_else(|| (0, split(abs, negative, units, self.rounding)));
+
+        let limit = match negative {
+            true => u128::from(i64::MIN.unsigned_abs()),
+            false => u128::from(i64::MAX.unsigned_abs()),
+        };
+        if rounded > limit {
+            return Err(ErrorKind::Overflow.into());
+        }
+
+        self.write_components(w, negative && rounded > 0, &counts, &units[last..])
+            .map_err(|_| ErrorKind::Format.into())
+    }
+
+    #[inline(always)]
+    #[doc(hidden)]
+    fn write_components<W: Write>(
+        &self,
+        w: &mut W,
+        negative: bool,
+        counts: &[u64],
+        units: &[Unit],
+    ) -> fmt::Result {
+        if negative {
+            w.write_char('-')?;
+        }
+        let mut components = counts.iter().zip(units).rev().filter(|(c, _)| **c > 0);
+        match components.next() {
+            Some((count, unit)) => self.write_component(w, *count, *unit)?,
+            None => return self.write_component(w, 0, units[0]),
+        }
+        for (count, unit) in components {
+            w.write_str(self.separator)?;
+            self.write_component(w, *count, *unit)?;
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    #[doc(hidden)]
+    fn write_component<W: Write>(&self, w: &mut W, count: u64, unit: Unit) -> fmt::Result {
+        match self.long {
+            true => write!(w, "{} {}", count, self.locale.unit_name(unit, count)),
+            false => write!(w, "{}{}", count, unit.short_name()),
+        }
+    }
+
+    /// Units of the output from the smallest to the largest one,
+    /// months, quarters, decades and centuries are replaced by the smaller units
+    #[inline(always)]
+    #[doc(hidden)]
+    fn units(&self) -> &'static [Unit] {
+        let smallest = self.smallest.max(Unit::Millisecond);
+        let largest = self.largest.max(smallest);
+        let position = |unit| {
+            Unit::FORMATTED
+                .iter()
+                .rposition(|u| *u <= unit)
+                .unwrap_or(0)
+        };
+        &Unit::FORMATTED[position(smallest)..=position(largest)]
+    }
+}
+
+impl Default for CompoundFormat<'static> {
+    fn default() -> Self {
+        CompoundFormat::new()
+    }
+}
+
+/// Splits the milliseconds count into the counts of the units from the smallest to the largest one.
+/// The rest, which is less than the smallest unit, is rounded with the rounding mode.
+/// Returns the counts and the rounded milliseconds count.
+#[inline(always)]
+#[doc(hidden)]
+fn split(
+    milliseconds: u128,
+    negative: bool,
+    units: &[Unit],
+    rounding: Rounding,
+) -> ([u64; Unit::FORMATTED.len()], u128) {
+    let mut counts = [0_u64; Unit::FORMATTED.len()];
+    let mut rest = milliseconds;
+    for (count, unit) in counts.iter_mut().zip(units).rev() {
+        let period = u128::from(unit.whole_millis());
+        *count = rest.div(period) as u64;
+        rest = rest.sub(u128::from(*count).mul(period));
+    }
+    let period = i128::from(units[0].whole_millis());
+    let value = i128::from(counts[0]).mul(period).add(rest as i128);
+    let value = match negative {
+        true => rounding.divide(value.neg(), period),
+        false => rounding.divide(value, period),
+    };
+    if value.unsigned_abs() > u128::from(counts[0]) {
+        counts[0] = counts[0].add(1);
+        // The carry goes to the larger unit only if it's a whole number of the smaller ones,
+        // a year is not a whole number of days or weeks.
+        for i in 1..units.len() {
+            let (smaller, larger) = (units[i.sub(1)].whole_millis(), units[i].whole_millis());
+            if larger.rem(smaller) != 0 || counts[i.sub(1)].mul(smaller) < larger {
+                break;
+            }
+            counts[i.sub(1)] = counts[i.sub(1)].sub(larger.div(smaller));
+            counts[i] = counts[i].add(1);
+        }
+    }
+    let rounded = counts
+        .iter()
+        .zip(units)
+        .map(|(count, unit)| u128::from(*count).mul(u128::from(unit.whole_millis())))
+        .sum();
+    (counts, rounded)
+}
@@ -0,0 +1,65 @@
+use crate::ErrorKind;
+
+/// Converting human-like times into milliseconds in the compilation time.
+/// `const_ms` is a `const fn`, which follows the same grammar as `ms` function,
+/// so time strings from `env!`, `include_str!` or `const` items can be converted into the const values.
+/// Values are calculated in the integer nanoseconds and rounded half away from zero into milliseconds.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::const_ms;
+///
+/// const TIMEOUT: i64 = const_ms("1.5h");
+/// assert_eq!(TIMEOUT, 5400000);
+///
+/// const INTERVAL: i64 = const_ms("1h 30m 15s");
+/// assert_eq!(INTERVAL, 5415000)
+/// ```
+///
+/// also you can a pass negative values
+/// ```
+/// use crate::ms_converter::const_ms;
+///
+/// const VALUE: i64 = const_ms("-2 days");
+/// assert_eq!(VALUE, -172800000)
+/// ```
+///
+/// `const_ms` **panics** on the invalid time string, so it's a compile error in the const context:
+/// ```compile_fail
+/// use crate::ms_converter::const_ms;
+///
+/// const VALUE: i64 = const_ms("1 dya");
+/// ```
+///
+/// Use `ms` function to handle the errors in the runtime.
+pub const fn const_ms(s: &str) -> i64 {
+    match parse_const_ms(s.as_bytes()) {
+        Ok(value) => value,
+        Err(kind) => panic!("{}", kind.message()),
+    }
+}
+
+/// The built-in units grammar of `ms_converter_core`, rounded into milliseconds.
+#[doc(hidden)]
+pub(crate) const fn parse_const_ms(s: &[u8]) -> Result<i64, ErrorKind> {
+    match ms_converter_core::parse(s) {
+        Ok((negative, total)) => round_nanos(negative, total),
+        Err(error) => Err(error.kind),
+    }
+}
+
+/// Rounds the nanoseconds count half away from zero into milliseconds, which fit into `i64`
+#[inline(always)]
+#[doc(hidden)]
+pub(crate) const fn round_nanos(negative: bool, total: u128) -> Result<i64, ErrorKind> {
+    // The division of `u128` is much slower, so the values, which fit into `u64`, are divided in `u64`.
+    let milliseconds = match total < (u64::MAX / 2) as u128 {
+        true => ((total as u64 + 500_000) / 1_000_000) as u128,
+        false => (total + 500_000) / 1_000_000,
+    };
+    match negative {
+        true if milliseconds <= 1 << 63 => Ok((milliseconds as i64).wrapping_neg()),
+        false if milliseconds < 1 << 63 => Ok(milliseconds as i64),
+        _ => Err(ErrorKind::Overflow),
+    }
+}
@@ -0,0 +1,140 @@
+use crate::fmt::Short;
+use crate::{ms, CompoundFormat, Error, ErrorKind};
+use core::convert::TryFrom;
+use core::fmt::Formatter;
+use core::str::FromStr;
+use core::time::Duration;
+
+/// Human-like time, which can be used as a field type in your config structs.
+/// `HumanDuration` keeps the milliseconds count, it's parsed by `ms` function
+/// and displayed by `get_compound_duration` function, so the displayed time is parsed back into the same value.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::HumanDuration;
+///
+/// let value: HumanDuration = "1h 30m".parse().unwrap();
+/// assert_eq!(value.as_millis(), 5400000);
+/// assert_eq!(value.to_string(), "1h 30m");
+/// assert_eq!(value.rounded().to_string(), "2h")
+/// ```
+///
+/// also you can convert it from and into `time.Duration`
+/// ```
+/// use crate::ms_converter::HumanDuration;
+/// use std::convert::TryFrom;
+/// use std::time::Duration;
+///
+/// let value = HumanDuration::try_from(Duration::from_secs(90)).unwrap();
+/// assert_eq!(value, HumanDuration::from_millis(90000));
+/// assert_eq!(Duration::try_from(value).unwrap(), Duration::from_secs(90))
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HumanDuration(i64);
+
+impl HumanDuration {
+    /// Zero duration
+    pub const ZERO: HumanDuration = HumanDuration(0);
+
+    /// Creates `HumanDuration` from the milliseconds count
+    pub const fn from_millis(milliseconds: i64) -> HumanDuration {
+        HumanDuration(milliseconds)
+    }
+
+    /// Returns the milliseconds count
+    pub const fn as_millis(self) -> i64 {
+        self.0
+    }
+
+    /// Returns `true` if the duration is less than zero
+    pub const fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    /// Checked addition, returns `None` if overflow occurred
+    pub fn checked_add(self, rhs: HumanDuration) -> Option<HumanDuration> {
+        self.0.checked_add(rhs.0).map(HumanDuration)
+    }
+
+    /// Checked subtraction, returns `None` if overflow occurred
+    pub fn checked_sub(self, rhs: HumanDuration) -> Option<HumanDuration> {
+        self.0.checked_sub(rhs.0).map(HumanDuration)
+    }
+
+    /// Checked multiplication by the number, returns `None` if overflow occurred
+    pub fn checked_mul(self, rhs: i64) -> Option<HumanDuration> {
+        self.0.checked_mul(rhs).map(HumanDuration)
+    }
+
+    /// Checked division by the number, returns `None` if `rhs == 0` or overflow occurred
+    pub fn checked_div(self, rhs: i64) -> Option<HumanDuration> {
+        self.0.checked_div(rhs).map(HumanDuration)
+    }
+
+    /// Checked negation, returns `None` if overflow occurred
+    pub fn checked_neg(self) -> Option<HumanDuration> {
+        self.0.checked_neg().map(HumanDuration)
+    }
+
+    /// Displays the duration rounded to the largest unit like `get_max_possible_duration` function,
+    /// the rounded time isn't parsed back into the same value
+    ///
+    /// ```
+    /// use crate::ms_converter::HumanDuration;
+    ///
+    /// assert_eq!(HumanDuration::from_millis(-90_000).rounded().to_string(), "-2m")
+    /// ```
+    pub const fn rounded(self) -> Short {
+        Short(self.0)
+    }
+}
+
+impl FromStr for HumanDuration {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ms(s).map(HumanDuration)
+    }
+}
+
+impl core::fmt::Display for HumanDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        CompoundFormat::new()
+            .write(f, self.0)
+            .map_err(|_| core::fmt::Error)
+    }
+}
+
+impl From<i64> for HumanDuration {
+    fn from(milliseconds: i64) -> Self {
+        HumanDuration(milliseconds)
+    }
+}
+
+impl From<HumanDuration> for i64 {
+    fn from(duration: HumanDuration) -> Self {
+        duration.0
+    }
+}
+
+impl TryFrom<Duration> for HumanDuration {
+    type Error = Error;
+
+    /// Converts `time.Duration` into `HumanDuration`, the part less than a millisecond is truncated.
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        i64::try_from(duration.as_millis())
+            .map(HumanDuration)
+            .map_err(|_| ErrorKind::Overflow.into())
+    }
+}
+
+impl TryFrom<HumanDuration> for Duration {
+    type Error = Error;
+
+    fn try_from(duration: HumanDuration) -> Result<Self, Self::Error> {
+        if duration.is_negative() {
+            return Err(ErrorKind::NegativeDuration.into());
+        }
+        Ok(Duration::from_millis(duration.0 as u64))
+    }
+}
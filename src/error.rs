@@ -0,0 +1,104 @@
+use core::fmt::Formatter;
+use core::ops::Range;
+
+pub(crate) use ms_converter_core::closest_postfix;
+pub use ms_converter_core::ErrorKind;
+
+/// Error which return `ms_converter` functions in runtime, if something is going wrong.
+/// Error knows the kind of the problem, the byte span of the bad token in your time string
+/// and the closest valid postfix for the unknown ones.
+///
+/// ```
+/// use crate::ms_converter::{ms, ErrorKind};
+///
+/// let error = ms("1h 30 mnis").unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::UnknownUnit);
+/// assert_eq!(error.span(), Some(6..10));
+/// assert_eq!(error.suggestion(), Some("mins"));
+/// assert_eq!(error.to_string(), "invalid postfix at 6..10, did you mean `mins`?")
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    kind: ErrorKind,
+    message: &'static str,
+    span: Option<(usize, usize)>,
+    suggestion: Option<&'static str>,
+}
+
+impl Error {
+    pub fn new(message: &'static str) -> Error {
+        Error {
+            kind: ErrorKind::Other,
+            message,
+            span: None,
+            suggestion: None,
+        }
+    }
+
+    /// Kind of the problem
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Human-readable description of the problem
+    pub fn message(&self) -> &'static str {
+        self.message
+    }
+
+    /// Byte span of the bad token in the time string
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.span.map(|(start, end)| start..end)
+    }
+
+    /// Closest valid postfix for the `ErrorKind::UnknownUnit` errors
+    pub fn suggestion(&self) -> Option<&'static str> {
+        self.suggestion
+    }
+
+    #[inline(always)]
+    #[doc(hidden)]
+    pub(crate) fn with_span(mut self, start: usize, end: usize) -> Error {
+        self.span = Some((start, end));
+        self
+    }
+
+    #[inline(always)]
+    #[doc(hidden)]
+    pub(crate) fn with_suggestion(mut self, suggestion: Option<&'static str>) -> Error {
+        self.suggestion = suggestion;
+        self
+    }
+}
+
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Error {
+        Error {
+            kind,
+            message: kind.message(),
+            span: None,
+            suggestion: None,
+        }
+    }
+}
+
+impl From<ms_converter_core::Error> for Error {
+    fn from(error: ms_converter_core::Error) -> Error {
+        Error::from(error.kind).with_span(error.start, error.end)
+    }
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.message)?;
+        if let Some((start, end)) = self.span {
+            write!(f, " at {}..{}", start, end)?;
+        }
+        if let Some(suggestion) = self.suggestion {
+            write!(f, ", did you mean `{}`?", suggestion)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
@@ -0,0 +1,104 @@
+use crate::Unit;
+use core::time::Duration;
+
+/// Number type of `ms_expr!` macro output.
+/// Integer outputs are rounded and checked in the compilation time,
+/// so `ms_expr!` fails to compile instead of saturating the value, if it doesn't fit into the type.
+#[doc(hidden)]
+pub trait MsExprOutput: Sized {
+    const FLOAT: bool;
+    const MIN: i128;
+    const MAX: u128;
+}
+
+macro_rules! impl_ms_expr_output {
+    (int $($type:ty),*) => {$(
+        impl MsExprOutput for $type {
+            const FLOAT: bool = false;
+            const MIN: i128 = <$type>::MIN as i128;
+            const MAX: u128 = <$type>::MAX as u128;
+        }
+    )*};
+    (float $($type:ty),*) => {$(
+        impl MsExprOutput for $type {
+            const FLOAT: bool = true;
+            const MIN: i128 = i128::MIN;
+            const MAX: u128 = u128::MAX;
+        }
+    )*};
+}
+
+impl_ms_expr_output!(int i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_ms_expr_output!(float f32, f64);
+
+/// Type of the literals in `ms_expr!` macro.
+/// The integer literals are `i128` instead of the default `i32`, so the large values, like `3000000000 ms`,
+/// don't overflow before they are converted.
+#[doc(hidden)]
+pub trait MsExprLiteral: Copy {}
+
+impl MsExprLiteral for i128 {}
+impl MsExprLiteral for f64 {}
+
+/// Conversions of `ms_expr!` macro from the nanoseconds count, which work in the const context.
+#[doc(hidden)]
+pub struct MsExpr;
+
+impl MsExpr {
+    /// Gives the literal the type of `MsExprLiteral`, `i128` for the integers and `f64` for the floats
+    #[doc(hidden)]
+    pub const fn literal<T: MsExprLiteral>(value: T) -> T {
+        value
+    }
+
+    /// Rounds the nanoseconds count half away from zero into the unit
+    /// and checks that it fits into the output type
+    #[doc(hidden)]
+    pub const fn integer<T: MsExprOutput>(nanoseconds: i128, unit: Unit) -> i128 {
+        let unit = unit.as_nanos() as i128;
+        let half = match nanoseconds < 0 {
+            true => -unit / 2,
+            false => unit / 2,
+        };
+        let value = (nanoseconds + half) / unit;
+        assert!(
+            value >= T::MIN && (value < 0 || value as u128 <= T::MAX),
+            "ms_expr! value doesn't fit into the output type"
+        );
+        value
+    }
+
+    /// Checks the literals and the units of the compound expression like `ms` function does:
+    /// the sign is allowed only at the start and every unit can be used only once
+    #[doc(hidden)]
+    pub const fn check(values: &[&str], units: &[Unit]) {
+        let mut i = 0;
+        while i < units.len() {
+            assert!(
+                values[i].as_bytes()[0] != b'-',
+                "sign is allowed only at the start"
+            );
+            let mut j = 0;
+            while j < i {
+                assert!(units[i] as u8 != units[j] as u8, "repeated postfix");
+                j += 1;
+            }
+            i += 1;
+        }
+    }
+
+    /// Converts the nanoseconds count into `time.Duration`
+    #[doc(hidden)]
+    pub const fn duration(nanoseconds: i128) -> Duration {
+        assert!(
+            nanoseconds >= 0,
+            "time.Duration cannot work with negative values"
+        );
+        let seconds = nanoseconds / 1_000_000_000;
+        assert!(
+            seconds <= u64::MAX as i128,
+            "ms_expr! value doesn't fit into time.Duration"
+        );
+        Duration::new(seconds as u64, (nanoseconds % 1_000_000_000) as u32)
+    }
+}
@@ -0,0 +1,28 @@
+//! Float functions, which are not available in `core`.
+//! With `std` feature they are the same as `f64` methods.
+
+/// Rounds half away from zero like `f64::round`
+#[cfg(feature = "std")]
+#[inline(always)]
+#[doc(hidden)]
+pub(crate) fn round(value: f64) -> f64 {
+    value.round()
+}
+
+/// Rounds half away from zero like `f64::round`
+#[cfg(not(feature = "std"))]
+#[inline(always)]
+#[doc(hidden)]
+pub(crate) fn round(value: f64) -> f64 {
+    // Every float from 2^52 is a whole number, NaN and infinities are kept too.
+    if value.is_nan() || value.abs() >= 4_503_599_627_370_496_f64 {
+        return value;
+    }
+    let whole = value as i64 as f64;
+    let rounded = match value - whole {
+        fraction if fraction >= 0.5 => whole + 1_f64,
+        fraction if fraction <= -0.5 => whole - 1_f64,
+        _ => whole,
+    };
+    rounded.copysign(value)
+}
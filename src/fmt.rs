@@ -0,0 +1,340 @@
+/*!
+Allocation-free formatting of the milliseconds count.
+
+Adapters implement `Display`, so they can be used in `format_args!`, `write!` and logging macros
+without the heap allocation, and `write_*` functions write the time into any `fmt::Write`.
+
+```
+use crate::ms_converter::fmt::{Long, Short, WithPostfix};
+
+assert_eq!(format!("{}", Short(5_400_000)), "2h");
+assert_eq!(format!("{}", Long(5_400_000)), "2 hours");
+assert_eq!(format!("{}", WithPostfix::new(5_400_000, " min")?), "90 min");
+# Ok::<(), ms_converter::Error>(())
+```
+
+```
+use crate::ms_converter::fmt::write_max_possible_duration;
+
+let mut value = String::new();
+write_max_possible_duration(&mut value, 86_400_000).unwrap();
+assert_eq!(value, "1d")
+```
+*/
+
+use crate::{get_byte_postfix, get_max_possible_unit, get_unit, go, iso};
+use crate::{Error, ErrorKind, Locale, Precision, Rounding, Unit};
+use core::fmt::{self, Display, Formatter, Write};
+
+/// Displays the milliseconds count like `get_max_possible_duration` function, e.g. `2d`.
+///
+/// ```
+/// use crate::ms_converter::fmt::Short;
+///
+/// assert_eq!(Short(-172_800_000).to_string(), "-2d")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Short(pub i64);
+
+impl Display for Short {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_max_possible_duration(f, self.0).map_err(|_| fmt::Error)
+    }
+}
+
+/// Displays the milliseconds count like `get_max_possible_duration_long` function, e.g. `2 days`.
+///
+/// ```
+/// use crate::ms_converter::fmt::Long;
+///
+/// assert_eq!(Long(-172_800_000).to_string(), "-2 days")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Long(pub i64);
+
+impl Display for Long {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_max_possible_duration_long(f, self.0).map_err(|_| fmt::Error)
+    }
+}
+
+/// Displays the milliseconds count like `get_max_possible_duration_long_localized` function,
+/// e.g. `2 дня` in Russian.
+///
+/// ```
+/// use crate::ms_converter::fmt::Localized;
+/// use crate::ms_converter::Locale;
+///
+/// assert_eq!(Localized(-172_800_000, Locale::Russian).to_string(), "-2 дня")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Localized(pub i64, pub Locale);
+
+impl Display for Localized {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_max_possible_duration_long_localized(
+            f,
+            self.0,
+            Unit::Day,
+            Rounding::HalfAwayFromZero,
+            self.1,
+        )
+        .map_err(|_| fmt::Error)
+    }
+}
+
+/// Displays the milliseconds count like `get_duration_by_postfix` function with your postfix, e.g. `48 hours`.
+/// The postfix is checked by `WithPostfix::new`, so the formatting never fails.
+///
+/// ```
+/// use crate::ms_converter::fmt::WithPostfix;
+///
+/// assert_eq!(WithPostfix::new(172_800_000, " hours")?.to_string(), "48 hours");
+/// assert!(WithPostfix::new(172_800_000, " dya").is_err());
+/// # Ok::<(), ms_converter::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WithPostfix<'a> {
+    value: i64,
+    postfix: &'a str,
+}
+
+impl<'a> WithPostfix<'a> {
+    /// Rounds the milliseconds count into the unit of the postfix,
+    /// the unknown postfix and the value, which doesn't fit into `i64`, are errors
+    pub fn new(milliseconds: i64, postfix: &'a str) -> Result<Self, Error> {
+        let unit = get_unit(get_byte_postfix(postfix))?;
+        let value = Rounding::HalfAwayFromZero.divide_millis(milliseconds, unit)?;
+        Ok(WithPostfix { value, postfix })
+    }
+}
+
+impl Display for WithPostfix<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.value, self.postfix)
+    }
+}
+
+/// Writes the milliseconds count like `get_duration_by_postfix` function into `fmt::Write`.
+///
+/// ```
+/// use crate::ms_converter::fmt::write_duration_by_postfix;
+///
+/// let mut value = String::new();
+/// write_duration_by_postfix(&mut value, 86_400_000, " day").unwrap();
+/// assert_eq!(value, "1 day")
+/// ```
+#[inline]
+pub fn write_duration_by_postfix<W: Write>(
+    w: &mut W,
+    milliseconds: i64,
+    postfix: &str,
+) -> Result<(), Error> {
+    write_duration_by_postfix_rounded(w, milliseconds, postfix, Rounding::HalfAwayFromZero)
+}
+
+/// Writes the milliseconds count like `get_max_possible_duration` function into `fmt::Write`.
+///
+/// ```
+/// use crate::ms_converter::fmt::write_max_possible_duration;
+///
+/// let mut value = String::new();
+/// write_max_possible_duration(&mut value, -86_400_000).unwrap();
+/// assert_eq!(value, "-1d")
+/// ```
+#[inline]
+pub fn write_max_possible_duration<W: Write>(w: &mut W, milliseconds: i64) -> Result<(), Error> {
+    write_max_possible_duration_rounded(w, milliseconds, Unit::Day, Rounding::HalfAwayFromZero)
+}
+
+/// Writes the milliseconds count like `get_max_possible_duration_long` function into `fmt::Write`.
+///
+/// ```
+/// use crate::ms_converter::fmt::write_max_possible_duration_long;
+///
+/// let mut value = String::new();
+/// write_max_possible_duration_long(&mut value, 86_400_000).unwrap();
+/// assert_eq!(value, "1 day")
+/// ```
+#[inline]
+pub fn write_max_possible_duration_long<W: Write>(
+    w: &mut W,
+    milliseconds: i64,
+) -> Result<(), Error> {
+    write_max_possible_duration_long_rounded(w, milliseconds, Unit::Day, Rounding::HalfAwayFromZero)
+}
+
+/// Writes the milliseconds count like `get_max_possible_duration_long_localized` function into `fmt::Write`
+/// with the largest unit and the rounding mode.
+/// The name of the unit follows the plural rules of the locale for the rounded value.
+///
+/// ```
+/// use crate::ms_converter::fmt::write_max_possible_duration_long_localized;
+/// use crate::ms_converter::{Locale, Rounding, Unit};
+///
+/// let mut value = String::new();
+/// write_max_possible_duration_long_localized(
+///     &mut value,
+///     1_900_000_000,
+///     Unit::Week,
+///     Rounding::Floor,
+///     Locale::Polish,
+/// )
+/// .unwrap();
+/// assert_eq!(value, "3 tygodnie")
+/// ```
+#[inline]
+pub fn write_max_possible_duration_long_localized<W: Write>(
+    w: &mut W,
+    milliseconds: i64,
+    largest: Unit,
+    rounding: Rounding,
+    locale: Locale,
+) -> Result<(), Error> {
+    let unit = get_max_possible_unit(milliseconds, largest);
+    let value = rounding.divide_millis(milliseconds, unit)?;
+    write!(
+        w,
+        "{} {}",
+        value,
+        locale.unit_name(unit, value.unsigned_abs())
+    )
+    .map_err(|_| ErrorKind::Format.into())
+}
+
+/// Writes the milliseconds count like `get_duration_by_postfix_fractional` function into `fmt::Write`.
+///
+/// ```
+/// use crate::ms_converter::fmt::write_duration_by_postfix_fractional;
+/// use crate::ms_converter::Precision;
+///
+/// let mut value = String::new();
+/// write_duration_by_postfix_fractional(&mut value, 5_400_000, "h", Precision::Max(2)).unwrap();
+/// assert_eq!(value, "1.5h")
+/// ```
+#[inline]
+pub fn write_duration_by_postfix_fractional<W: Write>(
+    w: &mut W,
+    milliseconds: i64,
+    postfix: &str,
+    precision: Precision,
+) -> Result<(), Error> {
+    let unit = get_unit(get_byte_postfix(postfix))?;
+    let value = precision.divide_millis(milliseconds, unit)?;
+    write!(w, "{}{}", value, postfix).map_err(|_| ErrorKind::Format.into())
+}
+
+/// Writes the milliseconds count like `get_max_possible_duration_fractional` function into `fmt::Write`.
+///
+/// ```
+/// use crate::ms_converter::fmt::write_max_possible_duration_fractional;
+/// use crate::ms_converter::{Precision, Unit};
+///
+/// let mut value = String::new();
+/// write_max_possible_duration_fractional(&mut value, 5_400_000, Unit::Day, Precision::Max(2))
+///     .unwrap();
+/// assert_eq!(value, "1.5h")
+/// ```
+#[inline]
+pub fn write_max_possible_duration_fractional<W: Write>(
+    w: &mut W,
+    milliseconds: i64,
+    largest: Unit,
+    precision: Precision,
+) -> Result<(), Error> {
+    let unit = get_max_possible_unit(milliseconds, largest);
+    let value = precision.divide_millis(milliseconds, unit)?;
+    write!(w, "{}{}", value, unit.short_name()).map_err(|_| ErrorKind::Format.into())
+}
+
+/// Writes the milliseconds count like `get_max_possible_duration_long_fractional` function into `fmt::Write`.
+///
+/// ```
+/// use crate::ms_converter::fmt::write_max_possible_duration_long_fractional;
+/// use crate::ms_converter::{Precision, Unit};
+///
+/// let mut value = String::new();
+/// write_max_possible_duration_long_fractional(&mut value, 5_400_000, Unit::Day, Precision::Max(2))
+///     .unwrap();
+/// assert_eq!(value, "1.5 hours")
+/// ```
+#[inline]
+pub fn write_max_possible_duration_long_fractional<W: Write>(
+    w: &mut W,
+    milliseconds: i64,
+    largest: Unit,
+    precision: Precision,
+) -> Result<(), Error> {
+    let unit = get_max_possible_unit(milliseconds, largest);
+    let value = precision.divide_millis(milliseconds, unit)?;
+    let count = match value.is_one() {
+        true => 1,
+        false => 2,
+    };
+    write!(w, "{} {}", value, unit.long_name(count)).map_err(|_| ErrorKind::Format.into())
+}
+
+/// Writes the milliseconds count like `get_iso_duration` function into `fmt::Write`.
+///
+/// ```
+/// use crate::ms_converter::fmt::write_iso_duration;
+///
+/// let mut value = String::new();
+/// write_iso_duration(&mut value, 5_400_000).unwrap();
+/// assert_eq!(value, "PT1H30M")
+/// ```
+#[inline]
+pub fn write_iso_duration<W: Write>(w: &mut W, milliseconds: i64) -> Result<(), Error> {
+    iso::write_iso_duration(w, milliseconds).map_err(|_| ErrorKind::Format.into())
+}
+
+/// Writes the nanoseconds count like `get_go_duration` function into `fmt::Write`.
+///
+/// ```
+/// use crate::ms_converter::fmt::write_go_duration;
+///
+/// let mut value = String::new();
+/// write_go_duration(&mut value, 3_723_500_000_000).unwrap();
+/// assert_eq!(value, "1h2m3.5s")
+/// ```
+#[inline]
+pub fn write_go_duration<W: Write>(w: &mut W, nanoseconds: i64) -> Result<(), Error> {
+    go::write_go_duration(w, nanoseconds).map_err(|_| ErrorKind::Format.into())
+}
+
+#[inline(always)]
+#[doc(hidden)]
+pub(crate) fn write_duration_by_postfix_rounded<W: Write>(
+    w: &mut W,
+    milliseconds: i64,
+    postfix: &str,
+    rounding: Rounding,
+) -> Result<(), Error> {
+    let unit = get_unit(get_byte_postfix(postfix))?;
+    let value = rounding.divide_millis(milliseconds, unit)?;
+    write!(w, "{}{}", value, postfix).map_err(|_| ErrorKind::Format.into())
+}
+
+#[inline(always)]
+#[doc(hidden)]
+pub(crate) fn write_max_possible_duration_rounded<W: Write>(
+    w: &mut W,
+    milliseconds: i64,
+    largest: Unit,
+    rounding: Rounding,
+) -> Result<(), Error> {
+    let unit = get_max_possible_unit(milliseconds, largest);
+    let value = rounding.divide_millis(milliseconds, unit)?;
+    write!(w, "{}{}", value, unit.short_name()).map_err(|_| ErrorKind::Format.into())
+}
+
+#[inline(always)]
+#[doc(hidden)]
+pub(crate) fn write_max_possible_duration_long_rounded<W: Write>(
+    w: &mut W,
+    milliseconds: i64,
+    largest: Unit,
+    rounding: Rounding,
+) -> Result<(), Error> {
+    write_max_possible_duration_long_localized(w, milliseconds, largest, rounding, Locale::English)
+}
@@ -0,0 +1,238 @@
+use crate::{error, Error, ErrorKind};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::{self, Write};
+use core::ops::{Div, Mul, Rem, Sub};
+
+/// How many nanoseconds in one microsecond
+const GO_MICROSECOND: u64 = 1_000;
+/// How many nanoseconds in one millisecond
+const GO_MILLISECOND: u64 = GO_MICROSECOND * 1_000;
+/// How many nanoseconds in one second
+const GO_SECOND: u64 = GO_MILLISECOND * 1_000;
+/// How many nanoseconds in one minute
+const GO_MINUTE: u64 = GO_SECOND * 60;
+/// How many nanoseconds in one hour
+const GO_HOUR: u64 = GO_MINUTE * 60;
+
+/// Every postfix `get_go_modification` knows, used for the suggestions in errors
+const GO_POSTFIXES: &[&str] = &["h", "m", "s", "ms", "us", "µs", "ns"];
+
+/// Converting Go `time.Duration` strings into nanoseconds.
+/// `ns_from_go` function gets an str slice or String and returns nanoseconds count,
+/// exactly as Go `time.ParseDuration` does.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::ns_from_go;
+///
+/// let value = ns_from_go("1h2m3.5s").unwrap();
+/// assert_eq!(value, 3723500000000)
+/// ```
+///
+/// Go rules are used for the values: every value must have a postfix, except a bare `0`,
+/// postfixes can be repeated, spaces are not allowed and the sign is allowed only at the start.
+/// ```
+/// use crate::ms_converter::ns_from_go;
+///
+/// assert_eq!(ns_from_go("-1.5h").unwrap(), -5400000000000);
+/// assert_eq!(ns_from_go("350µs").unwrap(), 350000);
+/// assert_eq!(ns_from_go("0").unwrap(), 0);
+/// assert!(ns_from_go("1").is_err());
+/// assert!(ns_from_go("1h 30m").is_err());
+/// ```
+///
+/// ### Supported postfixes
+/// * **Hours:** `h`
+/// * **Minutes:** `m`
+/// * **Seconds:** `s`
+/// * **Milliseconds:** `ms`
+/// * **Microseconds:** `us`, `µs` (micro sign), `μs` (greek mu)
+/// * **Nanoseconds:** `ns`
+pub fn ns_from_go<T>(s: T) -> Result<i64, Error>
+where
+    T: AsRef<str>,
+{
+    let s = s.as_ref();
+
+    parse_go(s.as_bytes())
+}
+
+/// Getting Go `time.Duration` string from nanoseconds.
+/// `get_go_duration` function gets a nanoseconds count and returns the same string
+/// as Go `time.Duration.String()` does.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::get_go_duration;
+///
+/// let value = get_go_duration(3723500000000).unwrap();
+/// assert_eq!(value, "1h2m3.5s")
+/// ```
+///
+/// Durations less than one second are written with the smaller postfixes:
+/// ```
+/// use crate::ms_converter::get_go_duration;
+///
+/// assert_eq!(get_go_duration(350000).unwrap(), "350µs");
+/// assert_eq!(get_go_duration(-1500000).unwrap(), "-1.5ms");
+/// assert_eq!(get_go_duration(0).unwrap(), "0s");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn get_go_duration(nanoseconds: i64) -> Result<String, Error> {
+    let mut go = String::with_capacity(32);
+    write_go_duration(&mut go, nanoseconds).map_err(|_| ErrorKind::Format)?;
+    Ok(go)
+}
+
+#[inline(always)]
+#[doc(hidden)]
+pub(crate) fn write_go_duration<W: Write>(w: &mut W, nanoseconds: i64) -> fmt::Result {
+    let abs = nanoseconds.unsigned_abs();
+    if nanoseconds < 0 {
+        w.write_char('-')?;
+    }
+
+    match abs {
+        0 => w.write_str("0s"),
+        a if a < GO_MICROSECOND => write!(w, "{}ns", a),
+        a if a < GO_MILLISECOND => write_go_fraction(w, a, GO_MICROSECOND, "µs"),
+        a if a < GO_SECOND => write_go_fraction(w, a, GO_MILLISECOND, "ms"),
+        a => {
+            let (hours, minutes) = (a.div(GO_HOUR), a.rem(GO_HOUR).div(GO_MINUTE));
+            if hours > 0 {
+                write!(w, "{}h{}m", hours, minutes)?;
+            } else if minutes > 0 {
+                write!(w, "{}m", minutes)?;
+            }
+            write_go_fraction(w, a.rem(GO_MINUTE), GO_SECOND, "s")
+        }
+    }
+}
+
+#[inline(always)]
+#[doc(hidden)]
+fn write_go_fraction<W: Write>(w: &mut W, value: u64, period: u64, postfix: &str) -> fmt::Result {
+    write!(w, "{}", value.div(period))?;
+
+    let (mut fraction, mut width) = (value.rem(period), period.ilog10() as usize);
+    if fraction > 0 {
+        while fraction.rem(10) == 0 {
+            fraction = fraction.div(10);
+            width = width.sub(1);
+        }
+        write!(w, ".{:0width$}", fraction, width = width)?;
+    }
+    w.write_str(postfix)
+}
+
+#[inline(always)]
+#[doc(hidden)]
+fn get_go_modification(postfix: &[u8]) -> Result<u64, Error> {
+    match postfix {
+        b"h" => Ok(GO_HOUR),
+        b"m" => Ok(GO_MINUTE),
+        b"s" => Ok(GO_SECOND),
+        b"ms" => Ok(GO_MILLISECOND),
+        // "µs" with the micro sign and "μs" with the greek mu
+        b"us" | b"\xC2\xB5s" | b"\xCE\xBCs" => Ok(GO_MICROSECOND),
+        b"ns" => Ok(1),
+        _ => Err(
+            Error::from(ErrorKind::UnknownUnit).with_suggestion(error::closest_postfix(
+                postfix,
+                GO_POSTFIXES.iter().copied(),
+            )),
+        ),
+    }
+}
+
+#[inline(always)]
+#[doc(hidden)]
+fn parse_go(s: &[u8]) -> Result<i64, Error> {
+    let offset = |part: &[u8]| (part.as_ptr() as usize).sub(s.as_ptr() as usize);
+    let (negative, mut rest) = match s.first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    match rest {
+        b"0" => return Ok(0),
+        b"" => return Err(Error::from(ErrorKind::Empty).with_span(0, s.len())),
+        _ => {}
+    }
+
+    let mut total = 0_u64;
+    while !rest.is_empty() {
+        let (value, tail) = rest.split_at(
+            rest.iter()
+                .position(|b| !b.is_ascii_digit())
+                .unwrap_or(rest.len()),
+        );
+        let (fraction, tail) = match tail.split_first() {
+            Some((b'.', tail)) => tail.split_at(
+                tail.iter()
+                    .position(|b| !b.is_ascii_digit())
+                    .unwrap_or(tail.len()),
+            ),
+            _ => (&tail[..0], tail),
+        };
+        let start = offset(value);
+        if value.is_empty() && fraction.is_empty() {
+            return Err(Error::from(ErrorKind::InvalidNumber).with_span(start, offset(tail)));
+        }
+
+        let (postfix, tail) = tail.split_at(
+            tail.iter()
+                .position(|b| matches!(b, b'0'..=b'9' | b'.'))
+                .unwrap_or(tail.len()),
+        );
+        let end = offset(tail);
+        if postfix.is_empty() {
+            return Err(Error::from(ErrorKind::MissingUnit).with_span(end, end));
+        }
+        let modification =
+            get_go_modification(postfix).map_err(|e| e.with_span(offset(postfix), end))?;
+
+        total = get_go_value(value, fraction, modification)
+            .and_then(|value| value.checked_add(total))
+            .filter(|total| *total <= 1 << 63)
+            .ok_or_else(|| Error::from(ErrorKind::Overflow).with_span(start, end))?;
+        rest = tail;
+    }
+
+    match (negative, total) {
+        (true, total) => Ok((total as i64).wrapping_neg()),
+        (false, total) if total < 1 << 63 => Ok(total as i64),
+        _ => Err(Error::from(ErrorKind::Overflow).with_span(0, s.len())),
+    }
+}
+
+#[inline(always)]
+#[doc(hidden)]
+fn get_go_value(value: &[u8], fraction: &[u8], modification: u64) -> Option<u64> {
+    let value = value.iter().try_fold(0_u64, |value, b| {
+        value
+            .checked_mul(10)?
+            .checked_add(b.sub(b'0') as u64)
+            .filter(|value| *value <= 1 << 63)
+    })?;
+    let value = value
+        .checked_mul(modification)
+        .filter(|value| *value <= 1 << 63)?;
+
+    // Like Go, ignore the fraction digits which are not fitting into the integer.
+    let (fraction, scale, _) = fraction.iter().map(|b| b.sub(b'0') as u64).fold(
+        (0_u64, 1_f64, false),
+        |(fraction, scale, overflow), b| match fraction
+            .checked_mul(10)
+            .and_then(|f| f.checked_add(b))
+        {
+            Some(f) if !overflow && f <= 1 << 63 => (f, scale.mul(10_f64), false),
+            _ => (fraction, scale, true),
+        },
+    );
+
+    value
+        .checked_add((fraction as f64).mul(modification as f64 / scale) as u64)
+        .filter(|value| *value <= 1 << 63)
+}
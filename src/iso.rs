@@ -0,0 +1,198 @@
+use crate::constant::round_nanos;
+use crate::{Error, ErrorKind, Unit, DAY, HOUR, MINUTE, SECOND};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::{self, Write};
+use core::ops::{Add, Div, Sub};
+
+const DATE_DESIGNATORS: &[(u8, Unit)] = &[
+    (b'Y', Unit::Year),
+    (b'M', Unit::Month),
+    (b'W', Unit::Week),
+    (b'D', Unit::Day),
+];
+const TIME_DESIGNATORS: &[(u8, Unit)] = &[
+    (b'H', Unit::Hour),
+    (b'M', Unit::Minute),
+    (b'S', Unit::Second),
+];
+
+/// Converting ISO 8601 durations into milliseconds.
+/// `ms_from_iso` function gets an str slice or String with ISO 8601 duration
+/// and returns the same milliseconds count as `ms` does for the human-like time.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::{ms, ms_from_iso};
+///
+/// let value = ms_from_iso("PT1H30M").unwrap();
+/// assert_eq!(value, ms("1h 30m").unwrap())
+/// ```
+///
+/// Fractions are allowed only in the last component, weeks and negative values are supported too:
+/// ```
+/// use crate::ms_converter::ms_from_iso;
+///
+/// assert_eq!(ms_from_iso("P3DT4H12M0.5S").unwrap(), 274320500);
+/// assert_eq!(ms_from_iso("P2W").unwrap(), 1209600000);
+/// assert_eq!(ms_from_iso("-PT1,5S").unwrap(), -1500);
+/// ```
+///
+/// ### Supported designators
+/// * **Years:** `Y`, the same year as in `ms`, 365.25 days
+/// * **Months:** `M` before `T`, a twelfth part of the year
+/// * **Weeks:** `W`
+/// * **Days:** `D`
+/// * **Hours:** `H`
+/// * **Minutes:** `M` after `T`
+/// * **Seconds:** `S`
+pub fn ms_from_iso<T>(s: T) -> Result<i64, Error>
+where
+    T: AsRef<str>,
+{
+    let s = s.as_ref();
+
+    let (negative, nanos) = parse_iso(s.as_bytes())?;
+    round_nanos(negative, nanos).map_err(|kind| Error::from(kind).with_span(0, s.len()))
+}
+
+/// Getting ISO 8601 duration from milliseconds.
+/// `get_iso_duration` function gets a milliseconds count and returns a canonical ISO 8601 duration.
+/// Only days, hours, minutes and seconds are written, because years and months have no fixed length,
+/// milliseconds are written as the fraction of seconds.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::{get_iso_duration, DAY, HOUR};
+///
+/// let value = get_iso_duration((3. * DAY + 4. * HOUR) as i64 + 500).unwrap();
+/// assert_eq!(value, "P3DT4H0.5S")
+/// ```
+///
+/// also you can a pass negative values
+/// ```
+/// use crate::ms_converter::{get_iso_duration, MINUTE};
+///
+/// let value = get_iso_duration(-90 * MINUTE as i64).unwrap();
+/// assert_eq!(value, "-PT1H30M")
+/// ```
+#[cfg(feature = "alloc")]
+pub fn get_iso_duration(milliseconds: i64) -> Result<String, Error> {
+    let mut iso = String::with_capacity(24);
+    write_iso_duration(&mut iso, milliseconds).map_err(|_| ErrorKind::Format)?;
+    Ok(iso)
+}
+
+#[inline(always)]
+#[doc(hidden)]
+pub(crate) fn write_iso_duration<W: Write>(w: &mut W, milliseconds: i64) -> fmt::Result {
+    let abs = milliseconds.unsigned_abs();
+    let days = abs / DAY as u64;
+    let hours = abs % DAY as u64 / HOUR as u64;
+    let minutes = abs % HOUR as u64 / MINUTE as u64;
+    let seconds = abs % MINUTE as u64 / SECOND as u64;
+    let mut millis = abs % SECOND as u64;
+
+    if milliseconds < 0 {
+        w.write_char('-')?;
+    }
+    w.write_char('P')?;
+    if days > 0 {
+        write!(w, "{}D", days)?;
+    }
+    if hours > 0 || minutes > 0 || seconds > 0 || millis > 0 || days == 0 {
+        w.write_char('T')?;
+    }
+    if hours > 0 {
+        write!(w, "{}H", hours)?;
+    }
+    if minutes > 0 {
+        write!(w, "{}M", minutes)?;
+    }
+    if millis > 0 {
+        let mut width = 3;
+        while millis % 10 == 0 {
+            millis = millis.div(10);
+            width = width.sub(1);
+        }
+        write!(w, "{}.{:0width$}S", seconds, millis, width = width)?;
+    } else if seconds > 0 || abs == 0 {
+        write!(w, "{}S", seconds)?;
+    }
+    Ok(())
+}
+
+#[inline(always)]
+#[doc(hidden)]
+fn parse_iso(s: &[u8]) -> Result<(bool, u128), Error> {
+    let offset = |part: &[u8]| (part.as_ptr() as usize).sub(s.as_ptr() as usize);
+    let (negative, rest) = match s.first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let mut rest = match rest.split_first() {
+        None | Some((b'P', [])) => {
+            return Err(Error::from(ErrorKind::Empty).with_span(0, s.len()));
+        }
+        Some((b'P', rest)) => rest,
+        Some(_) => {
+            return Err(
+                Error::from(ErrorKind::InvalidSyntax).with_span(offset(rest), offset(rest).add(1))
+            );
+        }
+    };
+
+    let mut section = DATE_DESIGNATORS;
+    let mut next = 0;
+    let mut total = 0_u128;
+    let mut fraction = false;
+    while let Some(&c) = rest.first() {
+        if c == b'T' && section != TIME_DESIGNATORS {
+            section = TIME_DESIGNATORS;
+            next = 0;
+            if rest.len() == 1 {
+                return Err(Error::from(ErrorKind::InvalidSyntax)
+                    .with_span(offset(rest), offset(rest).add(1)));
+            }
+            rest = &rest[1..];
+            continue;
+        }
+
+        let (value, tail) = rest.split_at(
+            rest.iter()
+                .position(|b| !matches!(b, b'0'..=b'9' | b'.' | b','))
+                .unwrap_or(rest.len()),
+        );
+        let (start, end) = (offset(value), offset(tail));
+        // The fraction needs the integer part, like `0.5S`, and not `.5S`.
+        if !matches!(value.first(), Some(b'0'..=b'9')) {
+            return Err(Error::from(ErrorKind::InvalidNumber).with_span(start, end));
+        }
+        if fraction {
+            return Err(Error::from(ErrorKind::InvalidSyntax).with_span(start, end));
+        }
+        fraction = value.iter().any(|b| matches!(b, b'.' | b','));
+
+        let designator = match tail.first() {
+            Some(designator) => designator,
+            None => return Err(Error::from(ErrorKind::MissingUnit).with_span(end, end)),
+        };
+        let kind = match section.iter().position(|(name, _)| name == designator) {
+            Some(position) if position >= next => {
+                total = ms_converter_core::parse_nanos(value, section[position].1.as_nanos())
+                    .and_then(|nanos| nanos.checked_add(total).ok_or(ErrorKind::Overflow))
+                    .map_err(|kind| Error::from(kind).with_span(start, end))?;
+                next = position.add(1);
+                rest = &tail[1..];
+                continue;
+            }
+            Some(position) if position.add(1) == next => ErrorKind::RepeatedUnit,
+            Some(_) => ErrorKind::InvalidSyntax,
+            None => ErrorKind::UnknownUnit,
+        };
+        return Err(Error::from(kind).with_span(end, end.add(1)));
+    }
+
+    Ok((negative, total))
+}
@@ -1,9 +1,10 @@
 /*!
 Fast abstraction for converting human-like times into milliseconds.
 
-There are two ways to calculate milliseconds:
+There are three ways to calculate milliseconds:
 * In the runtime `crate::ms_converter::ms`
 * In the compilation time `crate::ms_converter::ms_expr`
+* In the compilation time from a string `crate::ms_converter::const_ms`
 
 ## Usage
 
@@ -20,7 +21,21 @@ assert_eq!(value, 86400000)
 use crate::ms_converter::ms_expr;
 
 const VALUE: i64 = ms_expr!(i64, 1 d);
-assert_eq!(VALUE, 86400000)
+assert_eq!(VALUE, 86400000);
+
+const COMPOUND: i64 = ms_expr!(i64, 1 h 30 m);
+assert_eq!(COMPOUND, 5400000);
+
+const NANOS: u128 = ms_expr!(u128 as ns, 1.5 s);
+assert_eq!(NANOS, 1500000000)
+```
+
+### Convert time strings in the compilation step:
+```rust
+use crate::ms_converter::const_ms;
+
+const VALUE: i64 = const_ms("1.5h");
+assert_eq!(VALUE, 5400000)
 ```
 
 ### Convert ms into `time.Duration`
@@ -31,50 +46,299 @@ let value = ms_into_time("1d").unwrap();
 assert_eq!(value.as_millis(), 86400000)
 ```
 
+### Convert into `time.Duration` with nanoseconds precision
+```rust
+use crate::ms_converter::ns_into_time;
+
+let value = ns_into_time("1.0005s").unwrap();
+assert_eq!(value.as_nanos(), 1000500000)
+```
+
+### Use `HumanDuration` in your structs
+```rust
+use crate::ms_converter::HumanDuration;
+
+let value: HumanDuration = "1h 30m".parse().unwrap();
+assert_eq!(value.as_millis(), 5400000);
+assert_eq!(value.to_string(), "1h 30m");
+assert_eq!(value.rounded().to_string(), "2h")
+```
+
 ### Convert milliseconds into human-like time string
 ```
+# #[cfg(feature = "alloc")] {
 use crate::ms_converter::{get_duration_by_postfix, DAY};
 
 let value = get_duration_by_postfix(DAY as i64, " day").unwrap();
 assert_eq!(value, "1 day")
+# }
 ```
 
 ### Convert milliseconds into human-like time string without postfix
 ```
+# #[cfg(feature = "alloc")] {
 use crate::ms_converter::{get_max_possible_duration, DAY};
 
 let value = get_max_possible_duration(DAY as i64).unwrap();
 assert_eq!(value, "1d")
+# }
 ```
 
 ### Convert milliseconds into long human-like time string without postfix
 ```
+# #[cfg(feature = "alloc")] {
 use crate::ms_converter::{get_max_possible_duration_long, WEEK};
 
 let value = get_max_possible_duration_long(2 * WEEK as i64).unwrap();
 assert_eq!(value, "14 days") // Max possible period is a day
+# }
+```
+
+### Convert milliseconds into localized long human-like time string
+```
+# #[cfg(feature = "alloc")] {
+use crate::ms_converter::{get_max_possible_duration_long_localized, Locale, DAY};
+
+let value = get_max_possible_duration_long_localized(5 * DAY as i64, Locale::Russian).unwrap();
+assert_eq!(value, "5 дней")
+# }
+```
+
+### Convert milliseconds into human-like time string with weeks and years
+```
+# #[cfg(feature = "alloc")] {
+use crate::ms_converter::{get_max_possible_duration_long_up_to, Unit, WEEK};
+
+let value = get_max_possible_duration_long_up_to(2 * WEEK as i64, Unit::Year).unwrap();
+assert_eq!(value, "2 weeks")
+# }
+```
+
+### Choose the rounding mode of human-like time string
+```
+# #[cfg(feature = "alloc")] {
+use crate::ms_converter::{get_max_possible_duration_rounded, Rounding, Unit};
+
+let value = get_max_possible_duration_rounded(5_399_999, Unit::Day, Rounding::Floor).unwrap();
+assert_eq!(value, "1h")
+# }
+```
+
+### Convert milliseconds into fractional human-like time string
+```
+# #[cfg(feature = "alloc")] {
+use crate::ms_converter::{get_max_possible_duration_fractional, Precision, Unit};
+
+let value = get_max_possible_duration_fractional(5_400_000, Unit::Day, Precision::Max(2)).unwrap();
+assert_eq!(value, "1.5h")
+# }
+```
+
+### Convert milliseconds into compound human-like time string
+```
+# #[cfg(feature = "alloc")] {
+use crate::ms_converter::{get_compound_duration, CompoundFormat};
+
+let value = get_compound_duration(90061000).unwrap();
+assert_eq!(value, "1d 1h 1m 1s");
+
+let value = CompoundFormat::new().max_components(2).long(true).format(90061000).unwrap();
+assert_eq!(value, "1 day 1 hour")
+# }
+```
+
+### Format milliseconds without allocation
+```
+use crate::ms_converter::fmt::{Long, Short};
+
+let value = format!("elapsed {}, left {}", Short(5_400_000), Long(-60_000));
+assert_eq!(value, "elapsed 2h, left -1 minute")
+```
+
+### Convert ISO 8601 durations
+```rust
+# #[cfg(feature = "alloc")] {
+use crate::ms_converter::{get_iso_duration, ms_from_iso};
+
+let value = ms_from_iso("PT1H30M").unwrap();
+assert_eq!(value, 5400000);
+assert_eq!(get_iso_duration(value).unwrap(), "PT1H30M")
+# }
+```
+
+### Convert Go `time.Duration` strings
+```rust
+# #[cfg(feature = "alloc")] {
+use crate::ms_converter::{get_go_duration, ns_from_go};
+
+let value = ns_from_go("1h2m3.5s").unwrap();
+assert_eq!(value, 3723500000000);
+assert_eq!(get_go_duration(value).unwrap(), "1h2m3.5s")
+# }
+```
+
+### Parse user input leniently
+```rust
+use crate::ms_converter::Parser;
+
+let parser = Parser::new().lenient(true);
+assert_eq!(parser.ms("10 Minutes").unwrap(), 600000);
+assert_eq!(parser.ms(" 1H  30 min ").unwrap(), 5400000)
+```
+
+### Parse unit names in other languages
+```rust
+use crate::ms_converter::{Locale, Parser};
+
+let parser = Parser::new().locales(&[Locale::Russian, Locale::German]);
+assert_eq!(parser.ms("5 минут").unwrap(), 300000);
+assert_eq!(parser.ms("3 Tage").unwrap(), 259200000)
+```
+
+### Add your own units
+```rust
+# #[cfg(feature = "alloc")] {
+use crate::ms_converter::UnitRegistry;
+
+let registry = UnitRegistry::new()
+    .add_unit("sprint", "2w").unwrap()
+    .remove("m").unwrap();
+assert_eq!(registry.ms("1 sprint 2d").unwrap(), 1382400000);
+assert_eq!(registry.format(300000).unwrap(), "5min")
+# }
+```
+
+### Parse months, quarters, decades and centuries
+```rust
+# #[cfg(feature = "alloc")] {
+use crate::ms_converter::{ms, UnitRegistry, DAY, MONTH};
+
+assert_eq!(ms("6 months").unwrap(), 6 * MONTH as i64);
+let registry = UnitRegistry::new().define("month", "30d").unwrap();
+assert_eq!(registry.ms("6 mo").unwrap(), 6 * 30 * DAY as i64)
+# }
+```
+
+### Match the JavaScript `ms` package
+```rust
+# #[cfg(feature = "alloc")] {
+use crate::ms_converter::compat::vercel;
+
+assert_eq!(vercel::parse("1.5 Hours").unwrap(), 5400000.);
+assert_eq!(vercel::format_long(1499.).unwrap(), "1 second")
+# }
+```
+
+### Handle errors
+```rust
+use crate::ms_converter::{ms, ErrorKind};
+
+let error = ms("1 dya").unwrap_err();
+assert_eq!(error.kind(), ErrorKind::UnknownUnit);
+assert_eq!(error.span(), Some(2..5));
+assert_eq!(error.to_string(), "invalid postfix at 2..5, did you mean `day`?")
 ```
 
+### Use without std
+Disable default features to use `ms_converter` in `#![no_std]` crates.
+Parsing, `HumanDuration`, `CompoundFormat::write` and the `fmt` module work with `core` only,
+functions returning `String` and `UnitRegistry` need `alloc` feature.
+
+### Use with serde
+Enable `serde` feature and use `ms_converter::serde` modules with `#[serde(with = "...")]` attribute
+on `time.Duration`, `i64` and `Option<time.Duration>` fields.
+
+### Use the macros
+Enable `macros` feature to convert time string literals into constants with `ms!`, `ms_i64!` and `duration!`.
+The time strings follow the same grammar as `ms` function,
+and the invalid time string is a compile error, which points at the literal.
+
 ## Supported time strings
+* **Centuries:** `centuries`, `century`
+* **Decades:** `decades`, `decade`
 * **Years:** `years`, `year`, `yrs`, `yr`, `y`
+* **Quarters:** `quarters`, `quarter`, `q`
+* **Months:** `months`, `month`, `mo`
 * **Weeks:** `weeks`, `week`, `w`
 * **Days:** `days`, `day`, `d`
 * **Hours:** `hours`, `hour`, `hrs`, `hr`, `h`
 * **Minutes:** `minutes`, `minute`, `mins`, `min`, `m`
 * **Seconds:** `seconds`, `second`, `secs`, `sec`, `s`
 * **Milliseconds:** `milliseconds`, `millisecond`, `msecs`, `msec`, `ms` and empty postfix
+* **Microseconds:** `microseconds`, `microsecond`, `micros`, `us`, `µs`
+* **Nanoseconds:** `nanoseconds`, `nanosecond`, `nanos`, `ns`
+
+Several values can be combined into one compound time string, like `1h 30m`, `2d4h15m` or `2 days 4 hours`.
+Every postfix can be used only once and the sign is allowed only at the start of the string.
+
+The calendar units have the fixed length: a month is a twelfth of the year, which is 365.25 days,
+a quarter is 3 months, a decade is 10 years and a century is 100 years.
+`ms`, `const_ms`, `ms_expr!` and the macros always use these definitions,
+use `UnitRegistry::define` to parse a month as 30 days.
+The human-like time strings are never written in the calendar units.
 */
 
 #![doc(issue_tracker_base_url = "https://github.com/Mnwa/ms/issues/")]
 #![doc(html_root_url = "https://docs.rs/ms-converter/")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+use core::ops::{Add, Sub};
+use core::time::Duration;
+use ms_converter_core::parse_nanos;
 
-use std::borrow::Cow;
-use std::fmt::Formatter;
-use std::format;
-use std::ops::{Add, Mul, Sub};
-use std::string::String;
-use std::time::Duration;
+#[cfg(feature = "alloc")]
+pub use compound::get_compound_duration;
+pub use compound::CompoundFormat;
+pub use constant::const_ms;
+pub use duration::HumanDuration;
+pub use error::{Error, ErrorKind};
+#[doc(hidden)]
+pub use expr::{MsExpr, MsExprLiteral, MsExprOutput};
+#[cfg(feature = "alloc")]
+pub use go::get_go_duration;
+pub use go::ns_from_go;
+#[cfg(feature = "alloc")]
+pub use iso::get_iso_duration;
+pub use iso::ms_from_iso;
+pub use locale::{Locale, PluralCategory};
+#[cfg(feature = "macros")]
+pub use ms_converter_macros::{duration, ms, ms_i64};
+pub use parser::Parser;
+pub use precision::Precision;
+#[cfg(feature = "alloc")]
+pub use registry::UnitRegistry;
+pub use rounding::Rounding;
+pub use unit::Unit;
+
+pub mod compat;
+mod compound;
+mod constant;
+mod duration;
+mod error;
+mod expr;
+mod float;
+pub mod fmt;
+mod go;
+mod iso;
+mod locale;
+mod parser;
+mod precision;
+#[cfg(feature = "alloc")]
+mod registry;
+mod rounding;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod unit;
 
+/// How many milliseconds in one nanosecond
+pub const NANOSECOND: f64 = MICROSECOND / 1000_f64;
+/// How many milliseconds in one microsecond
+pub const MICROSECOND: f64 = 1_f64 / 1000_f64;
 /// How many milliseconds in one second
 pub const SECOND: f64 = 1000_f64;
 /// How many milliseconds in one minute
@@ -87,9 +351,21 @@ pub const DAY: f64 = HOUR * 24_f64;
 pub const WEEK: f64 = DAY * 7_f64;
 /// How many milliseconds in one year
 pub const YEAR: f64 = DAY * 365.25_f64;
+/// How many milliseconds in one month, which is a twelfth of the year or 30.4375 days
+pub const MONTH: f64 = YEAR / 12_f64;
+/// How many milliseconds in one quarter, which is a quarter of the year or 3 months
+pub const QUARTER: f64 = YEAR / 4_f64;
+/// How many milliseconds in one decade, which is 10 years
+pub const DECADE: f64 = YEAR * 10_f64;
+/// How many milliseconds in one century, which is 100 years
+pub const CENTURY: f64 = YEAR * 100_f64;
+
+/// How many different units can be used in one time string
+const UNITS_COUNT: usize = 32;
 
 /// Fast abstraction for converting human-like times into milliseconds.
 /// `ms` function gets an str slice or String and returns how much milliseconds in your pattern.
+/// Values are calculated in the integer nanoseconds and rounded half away from zero into milliseconds.
 ///
 /// ### Usage
 /// ```
@@ -99,30 +375,58 @@ pub const YEAR: f64 = DAY * 365.25_f64;
 /// assert_eq!(value, 86400000)
 /// ```
 ///
+/// Compound durations are summed up, every unit can be used only once:
+/// ```
+/// use crate::ms_converter::ms;
+///
+/// assert_eq!(ms("1h 30m").unwrap(), 5400000);
+/// assert_eq!(ms("2 days 4 hours").unwrap(), 187200000);
+/// assert_eq!(ms("-1h30m").unwrap(), -5400000);
+/// assert!(ms("1h 2h").is_err());
+/// assert!(ms("1h -30m").is_err());
+/// ```
+///
 /// ### Supported time strings
+/// * **Centuries:** `centuries`, `century`
+/// * **Decades:** `decades`, `decade`
 /// * **Years:** `years`, `year`, `yrs`, `yr`, `y`
+/// * **Quarters:** `quarters`, `quarter`, `q`
+/// * **Months:** `months`, `month`, `mo`
 /// * **Weeks:** `weeks`, `week`, `w`
 /// * **Days:** `days`, `day`, `d`
 /// * **Hours:** `hours`, `hour`, `hrs`, `hr`, `h`
 /// * **Minutes:** `minutes`, `minute`, `mins`, `min`, `m`
 /// * **Seconds:** `seconds`, `second`, `secs`, `sec`, `s`
 /// * **Milliseconds:** `milliseconds`, `millisecond`, `msecs`, `msec`, `ms` and empty postfix
+/// * **Microseconds:** `microseconds`, `microsecond`, `micros`, `us`, `µs`
+/// * **Nanoseconds:** `nanoseconds`, `nanosecond`, `nanos`, `ns`
 #[inline(always)]
-pub fn ms<'a, T>(s: T) -> Result<i64, Error>
+pub fn ms<T>(s: T) -> Result<i64, Error>
 where
-    T: Into<Cow<'a, str>>,
+    T: AsRef<str>,
 {
-    let s = &*s.into();
-
-    let (value, postfix): (&str, &str) = s
-        .find(|c: char| !matches!(c, '0'..='9' | '.' | '-' | '+'))
-        .map_or((s, ""), |vi| s.split_at(vi));
-
-    let postfix = get_byte_postfix(postfix);
+    Parser::new().ms(s)
+}
 
-    parse(value.as_bytes())
-        .and_then(move |value| Ok(get_modification(postfix)? * value))
-        .map(|v| v.round() as i64)
+/// Fast abstraction for converting human-like times into nanoseconds.
+/// `ns` function works like `ms`, but returns how much nanoseconds in your pattern,
+/// so microseconds and nanoseconds are not rounded to the whole milliseconds.
+/// Values are calculated without floating point numbers, every digit of your pattern is kept.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::{ms, ns};
+///
+/// assert_eq!(ns("250us").unwrap(), 250000);
+/// assert_eq!(ns("1ms 500ns").unwrap(), 1000500);
+/// assert_eq!(ms("250us").unwrap(), 0);
+/// ```
+#[inline(always)]
+pub fn ns<T>(s: T) -> Result<i64, Error>
+where
+    T: AsRef<str>,
+{
+    Parser::new().ns(s)
 }
 
 /// Getting human-like time from milliseconds.
@@ -151,33 +455,109 @@ where
 /// let value = get_duration_by_postfix(-DAY as i64, " day").unwrap();
 /// assert_eq!(value, "-1 day")
 /// ```
-
+///
 /// ### Supported postfixes
+/// * **Centuries:** `centuries`, `century`
+/// * **Decades:** `decades`, `decade`
 /// * **Years:** `years`, `year`, `yrs`, `yr`, `y`
+/// * **Quarters:** `quarters`, `quarter`, `q`
+/// * **Months:** `months`, `month`, `mo`
 /// * **Weeks:** `weeks`, `week`, `w`
 /// * **Days:** `days`, `day`, `d`
 /// * **Hours:** `hours`, `hour`, `hrs`, `hr`, `h`
 /// * **Minutes:** `minutes`, `minute`, `mins`, `min`, `m`
 /// * **Seconds:** `seconds`, `second`, `secs`, `sec`, `s`
 /// * **Milliseconds:** `milliseconds`, `millisecond`, `msecs`, `msec`, `ms` and empty postfix
+/// * **Microseconds:** `microseconds`, `microsecond`, `micros`, `us`, `µs`
+/// * **Nanoseconds:** `nanoseconds`, `nanosecond`, `nanos`, `ns`
+#[cfg(feature = "alloc")]
 #[inline]
 pub fn get_duration_by_postfix<'a, P>(milliseconds: i64, postfix: P) -> Result<String, Error>
 where
     P: Into<Cow<'a, str>>,
 {
-    let postfix = &*postfix.into();
-    let b_postfix = get_byte_postfix(postfix);
-    let v = get_modification(b_postfix)?;
-    Ok(format!(
-        "{}{}",
-        (milliseconds as f64 / v).round() as i64,
-        postfix
-    ))
+    get_duration_by_postfix_rounded(milliseconds, postfix, Rounding::HalfAwayFromZero)
+}
+
+/// Getting human-like time from milliseconds.
+/// `get_duration_by_postfix_rounded` function works like `get_duration_by_postfix`,
+/// but rounds the value with your rounding mode.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::{get_duration_by_postfix_rounded, Rounding};
+///
+/// let value = get_duration_by_postfix_rounded(1999, " sec", Rounding::Floor).unwrap();
+/// assert_eq!(value, "1 sec");
+///
+/// let value = get_duration_by_postfix_rounded(1001, " sec", Rounding::Ceil).unwrap();
+/// assert_eq!(value, "2 sec")
+/// ```
+///
+/// also you can a pass negative values
+/// ```
+/// use crate::ms_converter::{get_duration_by_postfix_rounded, Rounding};
+///
+/// let value = get_duration_by_postfix_rounded(-1999, "s", Rounding::Truncate).unwrap();
+/// assert_eq!(value, "-1s")
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn get_duration_by_postfix_rounded<'a, P>(
+    milliseconds: i64,
+    postfix: P,
+    rounding: Rounding,
+) -> Result<String, Error>
+where
+    P: Into<Cow<'a, str>>,
+{
+    let mut s = String::new();
+    fmt::write_duration_by_postfix_rounded(&mut s, milliseconds, &postfix.into(), rounding)?;
+    Ok(s)
+}
+
+/// Getting fractional human-like time from milliseconds.
+/// `get_duration_by_postfix_fractional` function works like `get_duration_by_postfix`,
+/// but writes the value with decimal places, like `1.5h`.
+/// The written value is parsed back by `ms` function to the same time within the precision.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::{get_duration_by_postfix_fractional, Precision};
+///
+/// let value = get_duration_by_postfix_fractional(5_400_000, "h", Precision::Max(3)).unwrap();
+/// assert_eq!(value, "1.5h");
+///
+/// let value = get_duration_by_postfix_fractional(194_400_000, " days", Precision::Fixed(3)).unwrap();
+/// assert_eq!(value, "2.250 days")
+/// ```
+///
+/// also you can a pass negative values
+/// ```
+/// use crate::ms_converter::{get_duration_by_postfix_fractional, Precision};
+///
+/// let value = get_duration_by_postfix_fractional(-1_500, "s", Precision::Max(3)).unwrap();
+/// assert_eq!(value, "-1.5s")
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn get_duration_by_postfix_fractional<'a, P>(
+    milliseconds: i64,
+    postfix: P,
+    precision: Precision,
+) -> Result<String, Error>
+where
+    P: Into<Cow<'a, str>>,
+{
+    let mut s = String::new();
+    fmt::write_duration_by_postfix_fractional(&mut s, milliseconds, &postfix.into(), precision)?;
+    Ok(s)
 }
 
 /// Getting human-like time from milliseconds.
 /// `get_max_possible_duration` function gets a milliseconds count and returns a max possible string with your time.
-/// `get_max_possible_duration` **has some limitations** maximum of avalable postfixes is a day.
+/// `get_max_possible_duration` **has some limitations** maximum of avalable postfixes is a day,
+/// use `get_max_possible_duration_up_to` for weeks and years.
 ///
 /// ### Usage
 /// ```
@@ -194,21 +574,112 @@ where
 /// let value = get_max_possible_duration(-2 * WEEK as i64).unwrap();
 /// assert_eq!(value, "-14d") // Max possible period is a day
 /// ```
+#[cfg(feature = "alloc")]
 #[inline]
 pub fn get_max_possible_duration(milliseconds: i64) -> Result<String, Error> {
-    let postfix = match milliseconds.abs() {
-        m if m >= DAY as i64 => "d",
-        m if m >= HOUR as i64 => "h",
-        m if m >= MINUTE as i64 => "m",
-        m if m >= SECOND as i64 => "s",
-        _ => "ms",
-    };
-    get_duration_by_postfix(milliseconds, postfix)
+    get_max_possible_duration_up_to(milliseconds, Unit::Day)
+}
+
+/// Getting human-like time from milliseconds.
+/// `get_max_possible_duration_up_to` function gets a milliseconds count and the largest unit
+/// and returns a max possible string with your time, which is not larger than the unit.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::{get_max_possible_duration_up_to, Unit, WEEK, YEAR};
+///
+/// let value = get_max_possible_duration_up_to(2 * WEEK as i64, Unit::Year).unwrap();
+/// assert_eq!(value, "2w");
+///
+/// let value = get_max_possible_duration_up_to(YEAR as i64, Unit::Year).unwrap();
+/// assert_eq!(value, "1y");
+///
+/// let value = get_max_possible_duration_up_to(YEAR as i64, Unit::Hour).unwrap();
+/// assert_eq!(value, "8766h")
+/// ```
+///
+/// also you can a pass negative values
+/// ```
+/// use crate::ms_converter::{get_max_possible_duration_up_to, Unit, WEEK};
+///
+/// let value = get_max_possible_duration_up_to(-2 * WEEK as i64, Unit::Week).unwrap();
+/// assert_eq!(value, "-2w")
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn get_max_possible_duration_up_to(milliseconds: i64, largest: Unit) -> Result<String, Error> {
+    get_max_possible_duration_rounded(milliseconds, largest, Rounding::HalfAwayFromZero)
+}
+
+/// Getting human-like time from milliseconds.
+/// `get_max_possible_duration_rounded` function works like `get_max_possible_duration_up_to`,
+/// but rounds the value with your rounding mode.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::{get_max_possible_duration_rounded, Rounding, Unit};
+///
+/// let value = get_max_possible_duration_rounded(5_399_999, Unit::Day, Rounding::Floor).unwrap();
+/// assert_eq!(value, "1h");
+///
+/// let value = get_max_possible_duration_rounded(3_600_001, Unit::Day, Rounding::Ceil).unwrap();
+/// assert_eq!(value, "2h")
+/// ```
+///
+/// also you can a pass negative values
+/// ```
+/// use crate::ms_converter::{get_max_possible_duration_rounded, Rounding, Unit};
+///
+/// let value = get_max_possible_duration_rounded(-5_400_000, Unit::Day, Rounding::HalfEven).unwrap();
+/// assert_eq!(value, "-2h")
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn get_max_possible_duration_rounded(
+    milliseconds: i64,
+    largest: Unit,
+    rounding: Rounding,
+) -> Result<String, Error> {
+    let mut s = String::new();
+    fmt::write_max_possible_duration_rounded(&mut s, milliseconds, largest, rounding)?;
+    Ok(s)
+}
+
+/// Getting fractional human-like time from milliseconds.
+/// `get_max_possible_duration_fractional` function works like `get_max_possible_duration_up_to`,
+/// but writes the value with decimal places, like `1.5h`.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::{get_max_possible_duration_fractional, Precision, Unit};
+///
+/// let value = get_max_possible_duration_fractional(5_400_000, Unit::Day, Precision::Max(2)).unwrap();
+/// assert_eq!(value, "1.5h");
+/// ```
+///
+/// also you can a pass negative values
+/// ```
+/// use crate::ms_converter::{get_max_possible_duration_fractional, Precision, Unit};
+///
+/// let value = get_max_possible_duration_fractional(-5_400_000, Unit::Day, Precision::Fixed(2)).unwrap();
+/// assert_eq!(value, "-1.50h");
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn get_max_possible_duration_fractional(
+    milliseconds: i64,
+    largest: Unit,
+    precision: Precision,
+) -> Result<String, Error> {
+    let mut s = String::new();
+    fmt::write_max_possible_duration_fractional(&mut s, milliseconds, largest, precision)?;
+    Ok(s)
 }
 
 /// Getting human-like time from milliseconds.
 /// `get_max_possible_duration_long` function gets a milliseconds count and returns a max possible string with your time.
-/// `get_max_possible_duration_long` **has some limitations** maximum of avalable postfixes is a day.
+/// `get_max_possible_duration_long` **has some limitations** maximum of avalable postfixes is a day,
+/// use `get_max_possible_duration_long_up_to` for weeks and years.
 ///
 /// ### Usage
 /// ```
@@ -232,30 +703,170 @@ pub fn get_max_possible_duration(milliseconds: i64) -> Result<String, Error> {
 /// let value = get_max_possible_duration_long(-2 * WEEK as i64).unwrap();
 /// assert_eq!(value, "-14 days") // Max possible period is a day
 /// ```
+#[cfg(feature = "alloc")]
 #[inline]
 pub fn get_max_possible_duration_long(milliseconds: i64) -> Result<String, Error> {
-    let postfix = match milliseconds.abs() {
-        m if m >= DAY as i64 => check_postfix(m, DAY, " day", " days"),
-        m if m >= HOUR as i64 => check_postfix(m, HOUR, " hour", " hours"),
-        m if m >= MINUTE as i64 => check_postfix(m, MINUTE, " minute", " minutes"),
-        m if m >= SECOND as i64 => check_postfix(m, SECOND, " second", " seconds"),
-        m => check_postfix(m, 1f64, " millisecond", " milliseconds"),
-    };
-    get_duration_by_postfix(milliseconds, postfix)
+    get_max_possible_duration_long_up_to(milliseconds, Unit::Day)
 }
 
+/// Getting human-like time from milliseconds.
+/// `get_max_possible_duration_long_up_to` function gets a milliseconds count and the largest unit
+/// and returns a max possible long string with your time, which is not larger than the unit.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::{get_max_possible_duration_long_up_to, Unit, WEEK, YEAR};
+///
+/// let value = get_max_possible_duration_long_up_to(2 * WEEK as i64, Unit::Year).unwrap();
+/// assert_eq!(value, "2 weeks");
+///
+/// let value = get_max_possible_duration_long_up_to(YEAR as i64, Unit::Year).unwrap();
+/// assert_eq!(value, "1 year")
+/// ```
+///
+/// also you can a pass negative values
+/// ```
+/// use crate::ms_converter::{get_max_possible_duration_long_up_to, Unit, WEEK};
+///
+/// let value = get_max_possible_duration_long_up_to(-WEEK as i64, Unit::Week).unwrap();
+/// assert_eq!(value, "-1 week")
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn get_max_possible_duration_long_up_to(
+    milliseconds: i64,
+    largest: Unit,
+) -> Result<String, Error> {
+    get_max_possible_duration_long_rounded(milliseconds, largest, Rounding::HalfAwayFromZero)
+}
+
+/// Getting human-like time from milliseconds.
+/// `get_max_possible_duration_long_rounded` function works like `get_max_possible_duration_long_up_to`,
+/// but rounds the value with your rounding mode.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::{get_max_possible_duration_long_rounded, Rounding, Unit, DAY};
+///
+/// let value = get_max_possible_duration_long_rounded(2 * DAY as i64 - 1, Unit::Day, Rounding::Floor)
+///     .unwrap();
+/// assert_eq!(value, "1 day")
+/// ```
+///
+/// also you can a pass negative values
+/// ```
+/// use crate::ms_converter::{get_max_possible_duration_long_rounded, Rounding, Unit, DAY};
+///
+/// let value = get_max_possible_duration_long_rounded(-DAY as i64 - 1, Unit::Day, Rounding::Floor)
+///     .unwrap();
+/// assert_eq!(value, "-2 days")
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn get_max_possible_duration_long_rounded(
+    milliseconds: i64,
+    largest: Unit,
+    rounding: Rounding,
+) -> Result<String, Error> {
+    let mut s = String::new();
+    fmt::write_max_possible_duration_long_rounded(&mut s, milliseconds, largest, rounding)?;
+    Ok(s)
+}
+
+/// Getting localized long human-like time from milliseconds.
+/// `get_max_possible_duration_long_localized` function works like `get_max_possible_duration_long`,
+/// but writes the name of the unit in the language of the locale.
+/// The name follows the plural rules of the language for the rounded value,
+/// so it's `1 день`, `2 дня` and `5 дней` in Russian.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::{get_max_possible_duration_long_localized, Locale, DAY};
+///
+/// let value = get_max_possible_duration_long_localized(22 * DAY as i64, Locale::Russian).unwrap();
+/// assert_eq!(value, "22 дня");
+///
+/// let value = get_max_possible_duration_long_localized(25 * DAY as i64, Locale::Polish).unwrap();
+/// assert_eq!(value, "25 dni");
+///
+/// let value = get_max_possible_duration_long_localized(1_499, Locale::German).unwrap();
+/// assert_eq!(value, "1 Sekunde")
+/// ```
+///
+/// also you can a pass negative values
+/// ```
+/// use crate::ms_converter::{get_max_possible_duration_long_localized, Locale, HOUR};
+///
+/// let value = get_max_possible_duration_long_localized(-3 * HOUR as i64, Locale::Ukrainian).unwrap();
+/// assert_eq!(value, "-3 години")
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn get_max_possible_duration_long_localized(
+    milliseconds: i64,
+    locale: Locale,
+) -> Result<String, Error> {
+    let mut s = String::new();
+    fmt::write_max_possible_duration_long_localized(
+        &mut s,
+        milliseconds,
+        Unit::Day,
+        Rounding::HalfAwayFromZero,
+        locale,
+    )?;
+    Ok(s)
+}
+
+/// Getting fractional human-like time from milliseconds.
+/// `get_max_possible_duration_long_fractional` function works like `get_max_possible_duration_long_up_to`,
+/// but writes the value with decimal places, like `2.25 days`.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::{get_max_possible_duration_long_fractional, Precision, Unit};
+///
+/// let value = get_max_possible_duration_long_fractional(194_400_000, Unit::Day, Precision::Max(2))
+///     .unwrap();
+/// assert_eq!(value, "2.25 days");
+///
+/// let value = get_max_possible_duration_long_fractional(86_400_000, Unit::Day, Precision::Max(2))
+///     .unwrap();
+/// assert_eq!(value, "1 day");
+/// ```
+///
+/// also you can a pass negative values
+/// ```
+/// use crate::ms_converter::{get_max_possible_duration_long_fractional, Precision, Unit};
+///
+/// let value = get_max_possible_duration_long_fractional(-90_000, Unit::Day, Precision::Max(2))
+///     .unwrap();
+/// assert_eq!(value, "-1.5 minutes");
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn get_max_possible_duration_long_fractional(
+    milliseconds: i64,
+    largest: Unit,
+    precision: Precision,
+) -> Result<String, Error> {
+    let mut s = String::new();
+    fmt::write_max_possible_duration_long_fractional(&mut s, milliseconds, largest, precision)?;
+    Ok(s)
+}
+
+/// Finds the largest unit, which is not larger than the milliseconds count and the largest allowed unit.
+/// Units less than a millisecond, months, quarters, decades and centuries are never used.
 #[inline(always)]
 #[doc(hidden)]
-fn check_postfix<'a>(
-    milliseconds: i64,
-    period: f64,
-    postfix: &'a str,
-    postfix_mul: &'a str,
-) -> &'a str {
-    if milliseconds as f64 >= 1.5 * period {
-        return postfix_mul;
-    }
-    postfix
+fn get_max_possible_unit(milliseconds: i64, largest: Unit) -> Unit {
+    let m = milliseconds.unsigned_abs();
+    Unit::FORMATTED
+        .iter()
+        .rev()
+        .filter(|unit| **unit <= largest && **unit > Unit::Millisecond)
+        .find(|unit| m >= unit.whole_millis())
+        .copied()
+        .unwrap_or(Unit::Millisecond)
 }
 
 #[inline(always)]
@@ -268,83 +879,126 @@ fn get_byte_postfix(postfix: &str) -> &[u8] {
     }
 }
 
+/// Finds the nanoseconds count of the postfix, it's the lookup function of `Parser`
+#[inline(always)]
+#[doc(hidden)]
+fn get_nanos(postfix: &[u8]) -> Result<u64, Error> {
+    get_unit(postfix).map(Unit::as_nanos)
+}
+
+#[inline(always)]
+#[doc(hidden)]
+fn get_unit(postfix: &[u8]) -> Result<Unit, Error> {
+    Unit::from_postfix_bytes(postfix).ok_or_else(|| {
+        Error::from(ErrorKind::UnknownUnit).with_suggestion(error::closest_postfix(
+            postfix,
+            ms_converter_core::postfixes(),
+        ))
+    })
+}
+
+#[inline(always)]
+#[doc(hidden)]
+fn parse_compound_nanos<M>(s: &[u8], parser: Parser, lookup: M) -> Result<(bool, u128), Error>
+where
+    M: Fn(&[u8]) -> Result<u64, Error>,
+{
+    let mut total = 0_u128;
+    let negative = for_each_segment(s, parser, lookup, |value, nanos| {
+        total = parse_nanos(value, nanos)?
+            .checked_add(total)
+            .ok_or(ErrorKind::Overflow)?;
+        Ok(())
+    })?;
+    Ok((negative, total))
+}
+
+/// Splits the compound time string into the values and the nanoseconds counts of their postfixes,
+/// which are found by the lookup function, like `get_nanos`.
+/// Returns `true` if the whole string is negative.
+/// The lenient mode ignores the case of postfixes and any whitespaces around the values and postfixes,
+/// the unit names of the parser locales are used, if the postfix is unknown.
 #[inline(always)]
 #[doc(hidden)]
-fn get_modification(postfix: &[u8]) -> Result<f64, Error> {
-    match postfix.first() {
-        Some(b'y') if matches!(postfix, b"years" | b"year" | b"yrs" | b"yr" | b"y") => Ok(YEAR),
-        Some(b'w') if matches!(postfix, b"weeks" | b"week" | b"w") => Ok(WEEK),
-        Some(b'd') if matches!(postfix, b"days" | b"day" | b"d") => Ok(DAY),
-        Some(b'h') if matches!(postfix, b"hours" | b"hour" | b"hrs" | b"hr" | b"h") => Ok(HOUR),
-        Some(b'm') if matches!(postfix, b"minutes" | b"minute" | b"mins" | b"min" | b"m") => {
-            Ok(MINUTE)
+fn for_each_segment<M, F>(s: &[u8], parser: Parser, lookup: M, mut f: F) -> Result<bool, Error>
+where
+    M: Fn(&[u8]) -> Result<u64, Error>,
+    F: FnMut(&[u8], u64) -> Result<(), Error>,
+{
+    let (negative, mut cursor) = ms_converter_core::start(s, parser.lenient)?;
+    let mut seen = [0_u64; UNITS_COUNT];
+    let mut seen_len = 0;
+    loop {
+        let part = ms_converter_core::segment(s, cursor)?;
+        let ((value_start, value_end), (postfix_start, mut postfix_end)) =
+            (part.value, part.postfix);
+        // The unit names of the locales can be two words, like `ملي ثانية` or `de jours`.
+        if let Some(len) = parser.join_words(&s[postfix_start..], postfix_end.sub(postfix_start)) {
+            postfix_end = postfix_start.add(len);
         }
-        None | Some(b'm')
-            if matches!(
-                postfix,
-                b"milliseconds" | b"millisecond" | b"msecs" | b"msec" | b"ms" | b""
-            ) =>
-        {
-            Ok(1f64)
+        let tail = ms_converter_core::tail(s, cursor, (postfix_start, postfix_end))?;
+
+        let nanos = parser
+            .nanos(&s[postfix_start..postfix_end], &lookup)
+            .map_err(|e| e.with_span(postfix_start, postfix_end))?;
+        if seen[..seen_len].contains(&nanos) {
+            return Err(Error::from(ErrorKind::RepeatedUnit).with_span(postfix_start, postfix_end));
         }
-        Some(b's') if matches!(postfix, b"seconds" | b"second" | b"secs" | b"sec" | b"s") => {
-            Ok(SECOND)
+        if seen_len == UNITS_COUNT {
+            return Err(Error::from(ErrorKind::TooManyUnits).with_span(postfix_start, postfix_end));
         }
-        _ => Err(Error::new("invalid postfix")),
+        seen[seen_len] = nanos;
+        seen_len = seen_len.add(1);
+        f(&s[value_start..value_end], nanos).map_err(|e| e.with_span(value_start, postfix_end))?;
+
+        cursor = match ms_converter_core::next(s, cursor, tail)? {
+            Some(cursor) => cursor,
+            None => return Ok(negative),
+        };
     }
 }
 
+/// Length of the longest postfix, which is lowercased in the lenient mode
+const MAX_POSTFIX_LEN: usize = 32;
+
+/// Lowercases the postfix into the buffer, the postfix longer than the buffer is returned as is
 #[inline(always)]
 #[doc(hidden)]
-fn parse(mut num: &[u8]) -> Result<f64, Error> {
-    let sign = match num.first() {
-        Some(b'-') => {
-            num = &num[1..];
-            -1_f64
-        }
-        Some(b'+') => {
-            num = &num[1..];
-            1_f64
+fn to_ascii_lowercase<'a>(postfix: &'a [u8], buffer: &'a mut [u8; MAX_POSTFIX_LEN]) -> &'a [u8] {
+    match buffer.get_mut(..postfix.len()) {
+        Some(buffer) => {
+            buffer.copy_from_slice(postfix);
+            buffer.make_ascii_lowercase();
+            buffer
         }
-        _ => 1_f64,
-    };
-    let (mut ind, mut dist) = num
-        .iter()
-        .take_while(|b| b.is_ascii_digit())
-        .map(|b| b.sub(b'0') as f64)
-        .fold((0, 0_f64), |(ind, dist), b| {
-            (ind.add(1), dist.mul_add(10_f64, b))
-        });
-
-    if matches!(num.get(ind), Some(b'.')) {
-        ind = ind.add(1)
+        None => postfix,
     }
+}
 
-    if ind < num.len() {
-        let (pow, temp) = num[ind..]
-            .iter()
-            .take_while(|b| b.is_ascii_digit())
-            .map(|b| b.sub(b'0') as f64)
-            .fold((1, 0_f64), |(pow, temp), b| {
-                (pow.add(1), temp.mul_add(10_f64, b))
-            });
-
-        ind = ind.add(pow as usize).sub(1);
-        let pow = pow.mul(-1_i32).add(1);
-        dist = dist.add(temp.mul((10_f64).powi(pow)));
+/// Skips the leading whitespaces in the lenient mode
+#[inline(always)]
+#[doc(hidden)]
+fn skip_whitespaces(s: &[u8], lenient: bool) -> &[u8] {
+    match lenient {
+        true => trim_start(s),
+        false => s,
     }
+}
 
-    if num.len() == ind {
-        Ok(dist.copysign(sign))
-    } else {
-        Err(Error::new("invalid value"))
-    }
+#[inline(always)]
+#[doc(hidden)]
+fn trim_start(s: &[u8]) -> &[u8] {
+    let start = s
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(s.len());
+    &s[start..]
 }
 
 /// Zero cost converter from human-like time into a number.
-/// In the first argument, you need to pass type of your number (`i64`, `f64` and etc).
-/// The second argument is human-time construction, like `1 day`, `2 h`.
-/// The output will be a number with type what you set in the first argument.
+/// In the first argument, you need to pass type of your number (`i64`, `f64` and etc) or `Duration`.
+/// The second argument is human-time construction, like `1 day`, `2 h` or `1 h 30 m`.
+/// The output will be a number of milliseconds with type what you set in the first argument.
 ///
 /// **This macro will be precalculated in compilation time.** Also, you can use ms_expr with constants:
 ///
@@ -361,36 +1015,217 @@ fn parse(mut num: &[u8]) -> Result<f64, Error> {
 ///
 /// assert_eq!(ms_expr!(i64, 1 d), 86400000)
 /// ```
+///
+/// Several values are summed up, like in the compound time strings of `ms` function,
+/// and the sign at the start is applied to the whole sum:
+/// ```
+/// use crate::ms_converter::ms_expr;
+///
+/// const VALUE: i64 = ms_expr!(i64, 1 h 30 m);
+/// assert_eq!(VALUE, 5400000);
+///
+/// const NEGATIVE: i64 = ms_expr!(i64, -1 h 30 m);
+/// assert_eq!(NEGATIVE, -5400000)
+/// ```
+///
+/// Values are calculated in the integer nanoseconds for the integer output and `Duration`,
+/// so the fractional values are rounded half away from zero,
+/// `f32` and `f64` outputs are calculated in `f64` without rounding:
+/// ```
+/// use crate::ms_converter::ms_expr;
+///
+/// const VALUE: i64 = ms_expr!(i64, 1.5 s);
+/// assert_eq!(VALUE, 1500);
+///
+/// const ROUNDED: i64 = ms_expr!(i64, 2.5 us);
+/// assert_eq!(ROUNDED, 0);
+///
+/// const FLOAT: f64 = ms_expr!(f64, 2.5 us);
+/// assert_eq!(FLOAT, 0.0025)
+/// ```
+///
+/// Use `as` with the postfix to get the number in other units, like nanoseconds or microseconds:
+/// ```
+/// use crate::ms_converter::ms_expr;
+///
+/// const NANOS: u128 = ms_expr!(u128 as ns, 1.5 s);
+/// assert_eq!(NANOS, 1500000000);
+///
+/// const MICROS: u64 = ms_expr!(u64 as us, 1 ms 500 ns);
+/// assert_eq!(MICROS, 1001)
+/// ```
+///
+/// `Duration`, `std::time::Duration` and `core::time::Duration` keep the nanoseconds precision:
+/// ```
+/// use crate::ms_converter::ms_expr;
+/// use std::time::Duration;
+///
+/// static TIMEOUT: Duration = ms_expr!(Duration, 2 d 4 h);
+/// assert_eq!(TIMEOUT, Duration::from_secs(187200));
+///
+/// const DELAY: Duration = ms_expr!(Duration, 1.5 s 250 ns);
+/// assert_eq!(DELAY, Duration::new(1, 500000250))
+/// ```
+///
+/// ### Supported postfixes
+/// * **Centuries:** `centuries`, `century`
+/// * **Decades:** `decades`, `decade`
+/// * **Years:** `years`, `year`, `yrs`, `yr`, `y`
+/// * **Quarters:** `quarters`, `quarter`, `q`
+/// * **Months:** `months`, `month`, `mo`
+/// * **Weeks:** `weeks`, `week`, `w`
+/// * **Days:** `days`, `day`, `d`
+/// * **Hours:** `hours`, `hour`, `hrs`, `hr`, `h`
+/// * **Minutes:** `minutes`, `minute`, `mins`, `min`, `m`
+/// * **Seconds:** `seconds`, `second`, `secs`, `sec`, `s`
+/// * **Milliseconds:** `milliseconds`, `millisecond`, `msecs`, `msec`, `ms` and empty postfix
+/// * **Microseconds:** `microseconds`, `microsecond`, `micros`, `us`
+/// * **Nanoseconds:** `nanoseconds`, `nanosecond`, `nanos`, `ns`
+///
+/// The value is checked in the compilation time, so the overflow of the number type is a compile error:
+/// ```compile_fail
+/// use crate::ms_converter::ms_expr;
+///
+/// let value = ms_expr!(i32, 25 d);
+/// ```
+///
+/// ```compile_fail
+/// use crate::ms_converter::ms_expr;
+///
+/// let value = ms_expr!(i32, 1 y);
+/// ```
+///
+/// ```compile_fail
+/// use crate::ms_converter::ms_expr;
+///
+/// let value = ms_expr!(i32, 24 d 1 d);
+/// ```
+///
+/// ```compile_fail
+/// use crate::ms_converter::ms_expr;
+///
+/// let value = ms_expr!(u64, -1 s);
+/// ```
+///
+/// The compound values follow the rules of `ms` function,
+/// so the sign in the middle and the repeated unit are compile errors too:
+/// ```compile_fail
+/// use crate::ms_converter::ms_expr;
+///
+/// let value = ms_expr!(i64, 1 h -30 m);
+/// ```
+///
+/// ```compile_fail
+/// use crate::ms_converter::ms_expr;
+///
+/// let value = ms_expr!(i64, 1 h 2 h);
+/// ```
 #[macro_export]
 macro_rules! ms_expr {
-    ($type:ty, $x:literal $(milliseconds)?$(millisecond)?$(msecs)?$(msec)?$(ms)?) => {{
-        let x: $type = $x;
-        x
-    }};
-    ($type:ty, $x:literal $(seconds)?$(second)?$(secs)?$(sec)?$(s)?) => {{
-        let x: $type = $x * ($crate::SECOND as $type);
-        x
-    }};
-    ($type:ty, $x:literal $(minutes)?$(minute)?$(mins)?$(min)?$(m)?) => {{
-        let x: $type = $x * ($crate::MINUTE as $type);
-        x
+    (@unit $(nanoseconds)?$(nanosecond)?$(nanos)?$(ns)?) => {
+        $crate::Unit::Nanosecond
+    };
+    (@unit $(microseconds)?$(microsecond)?$(micros)?$(us)?) => {
+        $crate::Unit::Microsecond
+    };
+    (@unit $(milliseconds)?$(millisecond)?$(msecs)?$(msec)?$(ms)?) => {
+        $crate::Unit::Millisecond
+    };
+    (@unit $(seconds)?$(second)?$(secs)?$(sec)?$(s)?) => {
+        $crate::Unit::Second
+    };
+    (@unit $(minutes)?$(minute)?$(mins)?$(min)?$(m)?) => {
+        $crate::Unit::Minute
+    };
+    (@unit $(hours)?$(hour)?$(hrs)?$(hr)?$(h)?) => {
+        $crate::Unit::Hour
+    };
+    (@unit $(days)?$(day)?$(d)?) => {
+        $crate::Unit::Day
+    };
+    (@unit $(weeks)?$(week)?$(w)?) => {
+        $crate::Unit::Week
+    };
+    (@unit $(months)?$(month)?$(mo)?) => {
+        $crate::Unit::Month
+    };
+    (@unit $(quarters)?$(quarter)?$(q)?) => {
+        $crate::Unit::Quarter
+    };
+    (@unit $(years)?$(year)?$(yrs)?$(yr)?$(y)?) => {
+        $crate::Unit::Year
+    };
+    (@unit $(decades)?$(decade)?) => {
+        $crate::Unit::Decade
+    };
+    (@unit $(centuries)?$(century)?) => {
+        $crate::Unit::Century
+    };
+    (@nanos - $($x:literal $unit:ident)+) => {
+        -$crate::ms_expr!(@nanos $($x $unit)+)
+    };
+    (@nanos - $x:literal) => {
+        -$crate::ms_expr!(@nanos $x ms)
+    };
+    (@nanos $x:literal) => {
+        $crate::ms_expr!(@nanos $x ms)
+    };
+    (@nanos $($x:literal $unit:ident)+) => {{
+        $crate::ms_expr!(@check $($x $unit)+);
+        0 $(+ $crate::ms_expr!(@segment $x, $crate::ms_expr!(@unit $unit).as_nanos() as i128))+
     }};
-    ($type:ty, $x:literal $(hours)?$(hour)?$(hrs)?$(hr)?$(h)?) => {{
-        let x: $type = $x * ($crate::HOUR as $type);
-        x
+    (@float $output:ident, - $($x:literal $unit:ident)+) => {
+        -$crate::ms_expr!(@float $output, $($x $unit)+)
+    };
+    (@float $output:ident, - $x:literal) => {
+        -$crate::ms_expr!(@float $output, $x ms)
+    };
+    (@float $output:ident, $x:literal) => {
+        $crate::ms_expr!(@float $output, $x ms)
+    };
+    (@float $output:ident, $($x:literal $unit:ident)+) => {{
+        $crate::ms_expr!(@check $($x $unit)+);
+        // The floating point arithmetic is kept in the const item, `const fn` can't use it before Rust 1.82.
+        0. $(+ $crate::MsExpr::literal($x) as f64
+            * ($crate::ms_expr!(@unit $unit).as_nanos() as f64
+                / $crate::ms_expr!(@unit $output).as_nanos() as f64))+
     }};
-    ($type:ty, $x:literal $(days)?$(day)?$(d)?) => {{
-        let x: $type = $x * ($crate::DAY as $type);
-        x
+    // The sign is allowed only at the start and every unit can be used only once, like in `ms`.
+    (@check $($x:literal $unit:ident)+) => {
+        const _: () = $crate::MsExpr::check(
+            &[$(stringify!($x)),+],
+            &[$($crate::ms_expr!(@unit $unit)),+],
+        );
+    };
+    // The integer part is exact, only the fraction of the literal goes through `f64`.
+    (@segment $x:literal, $unit:expr) => {{
+        let x = $crate::MsExpr::literal($x);
+        let fraction = (x as f64 - (x as i128) as f64) * $unit as f64;
+        (x as i128) * $unit + (fraction + if fraction < 0. { -0.5 } else { 0.5 }) as i128
     }};
-    ($type:ty, $x:literal $(weeks)?$(week)?$(w)?) => {{
-        let x: $type = $x * ($crate::WEEK as $type);
-        x
+    (Duration, $($expr:tt)+) => {{
+        const X: ::core::time::Duration = $crate::MsExpr::duration($crate::ms_expr!(@nanos $($expr)+));
+        X
     }};
-    ($type:ty, $x:literal $(years)?$(year)?$(yrs)?$(yr)?$(y)?) => {{
-        let x: $type = $x * ($crate::YEAR as $type);
-        x
+    (std::time::Duration, $($expr:tt)+) => {
+        $crate::ms_expr!(Duration, $($expr)+)
+    };
+    (core::time::Duration, $($expr:tt)+) => {
+        $crate::ms_expr!(Duration, $($expr)+)
+    };
+    ($type:ty as $output:ident, $($expr:tt)+) => {{
+        const X: $type = match <$type as $crate::MsExprOutput>::FLOAT {
+            true => $crate::ms_expr!(@float $output, $($expr)+) as $type,
+            false => $crate::MsExpr::integer::<$type>(
+                $crate::ms_expr!(@nanos $($expr)+),
+                $crate::ms_expr!(@unit $output),
+            ) as $type,
+        };
+        X
     }};
+    ($type:ty, $($expr:tt)+) => {
+        $crate::ms_expr!($type as ms, $($expr)+)
+    };
 }
 
 /// Ms into time is the abstraction on `ms` function, which converts result into `time.Duration` type.
@@ -410,36 +1245,40 @@ macro_rules! ms_expr {
 /// let value = ms_into_time("1d").unwrap();
 /// assert_eq!(value.as_millis(), 86400000)
 /// ```
-pub fn ms_into_time<'a, T>(s: T) -> Result<Duration, Error>
+pub fn ms_into_time<T>(s: T) -> Result<Duration, Error>
 where
-    T: Into<Cow<'a, str>>,
+    T: AsRef<str>,
 {
-    let milliseconds = ms(s)?;
-    if milliseconds < 0 {
-        return Err(Error::new("time.Duration cannot work with negative values"));
-    }
-    Ok(Duration::from_millis(milliseconds as u64))
-}
-
-/// Error which return `ms_converter` functions in runtime, if something is going wrong.
-#[derive(Debug)]
-pub struct Error {
-    message: &'static str,
+    Parser::new().ms_into_time(s)
 }
 
-impl Error {
-    pub fn new(message: &'static str) -> Error {
-        Error { message }
-    }
-}
-
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.message)
-    }
+/// Ns into time is the full precision version of `ms_into_time`.
+/// `ns_into_time` function gets an str slice or String and returns `time.Duration`,
+/// which keeps every digit of your pattern down to nanoseconds without rounding to the milliseconds.
+/// `ns_into_time` **has the same limitations** as `ms_into_time`, it's not working with negative values:
+/// ```
+/// use crate::ms_converter::ns_into_time;
+///
+/// let value = ns_into_time("-1d").is_err();
+/// assert_eq!(value, true)
+/// ```
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::ns_into_time;
+///
+/// let value = ns_into_time("1.0005s").unwrap();
+/// assert_eq!(value.as_nanos(), 1000500000);
+///
+/// let value = ns_into_time("0.1s").unwrap();
+/// assert_eq!(value.as_nanos(), 100000000)
+/// ```
+pub fn ns_into_time<T>(s: T) -> Result<Duration, Error>
+where
+    T: AsRef<str>,
+{
+    Parser::new().ns_into_time(s)
 }
 
-impl std::error::Error for Error {}
-
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests;
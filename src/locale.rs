@@ -0,0 +1,726 @@
+use crate::Unit;
+
+/// Languages of the long human-like times with the built-in names of the units.
+/// The name of the unit depends on the plural category of the count like in
+/// [CLDR plural rules](https://cldr.unicode.org/index/cldr-spec/plural-rules),
+/// so the counts are written as `1 день`, `2 дня` and `5 дней` in Russian.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::{Locale, PluralCategory, Unit};
+///
+/// assert_eq!(Locale::Russian.unit_name(Unit::Day, 1), "день");
+/// assert_eq!(Locale::Russian.unit_name(Unit::Day, 3), "дня");
+/// assert_eq!(Locale::Russian.unit_name(Unit::Day, 11), "дней");
+/// assert_eq!(Locale::Polish.plural_category(22), PluralCategory::Few);
+/// assert_eq!(Locale::from_tag("pl-PL"), Some(Locale::Polish));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Locale {
+    /// English, `en`
+    #[default]
+    English,
+    /// German, `de`
+    German,
+    /// French, `fr`
+    French,
+    /// Spanish, `es`
+    Spanish,
+    /// Russian, `ru`
+    Russian,
+    /// Ukrainian, `uk`
+    Ukrainian,
+    /// Polish, `pl`
+    Polish,
+    /// Arabic, `ar`
+    Arabic,
+}
+
+/// Plural categories of [CLDR plural rules](https://cldr.unicode.org/index/cldr-spec/plural-rules),
+/// every language uses only some of them and `Other` is used by every language.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::{Locale, PluralCategory};
+///
+/// assert_eq!(Locale::English.plural_category(1), PluralCategory::One);
+/// assert_eq!(Locale::English.plural_category(0), PluralCategory::Other);
+/// assert_eq!(Locale::Arabic.plural_category(2), PluralCategory::Two);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+/// Names of the units from nanoseconds to centuries in the order of `Unit::ALL`
+type Names = [&'static str; 13];
+
+impl Locale {
+    /// Every built-in locale
+    pub const ALL: [Locale; 8] = [
+        Locale::English,
+        Locale::German,
+        Locale::French,
+        Locale::Spanish,
+        Locale::Russian,
+        Locale::Ukrainian,
+        Locale::Polish,
+        Locale::Arabic,
+    ];
+
+    /// The language subtag of the locale, like `ru`
+    pub const fn tag(self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::German => "de",
+            Locale::French => "fr",
+            Locale::Spanish => "es",
+            Locale::Russian => "ru",
+            Locale::Ukrainian => "uk",
+            Locale::Polish => "pl",
+            Locale::Arabic => "ar",
+        }
+    }
+
+    /// Finds the locale by the language tag, like `ru`, `pt-BR` or `en_US`.
+    /// Only the language subtag is used and its case is ignored.
+    ///
+    /// ```
+    /// use crate::ms_converter::Locale;
+    ///
+    /// assert_eq!(Locale::from_tag("DE-at"), Some(Locale::German));
+    /// assert_eq!(Locale::from_tag("uk_UA"), Some(Locale::Ukrainian));
+    /// assert_eq!(Locale::from_tag("ja"), None);
+    /// ```
+    pub fn from_tag(tag: &str) -> Option<Locale> {
+        let language = tag.split(['-', '_']).next().unwrap_or_default();
+        Locale::ALL
+            .iter()
+            .find(|locale| locale.tag().eq_ignore_ascii_case(language))
+            .copied()
+    }
+
+    /// The plural category of the integer count in the locale
+    pub const fn plural_category(self, count: u64) -> PluralCategory {
+        let (n10, n100) = (count % 10, count % 100);
+        let millions = count != 0 && count % 1_000_000 == 0;
+        match self {
+            Locale::English | Locale::German if count == 1 => PluralCategory::One,
+            Locale::English | Locale::German => PluralCategory::Other,
+            Locale::French if count == 0 || count == 1 => PluralCategory::One,
+            Locale::Spanish if count == 1 => PluralCategory::One,
+            Locale::French | Locale::Spanish if millions => PluralCategory::Many,
+            Locale::French | Locale::Spanish => PluralCategory::Other,
+            Locale::Russian | Locale::Ukrainian if n10 == 1 && n100 != 11 => PluralCategory::One,
+            Locale::Polish if count == 1 => PluralCategory::One,
+            Locale::Russian | Locale::Ukrainian | Locale::Polish
+                if matches!(n10, 2..=4) && !matches!(n100, 12..=14) =>
+            {
+                PluralCategory::Few
+            }
+            Locale::Russian | Locale::Ukrainian | Locale::Polish => PluralCategory::Many,
+            Locale::Arabic => match count {
+                0 => PluralCategory::Zero,
+                1 => PluralCategory::One,
+                2 => PluralCategory::Two,
+                _ if matches!(n100, 3..=10) => PluralCategory::Few,
+                _ if matches!(n100, 11..=99) => PluralCategory::Many,
+                _ => PluralCategory::Other,
+            },
+        }
+    }
+
+    /// The long name of the unit for the integer count, like `hour` or `hours`
+    pub fn unit_name(self, unit: Unit, count: u64) -> &'static str {
+        let category = self.plural_category(count);
+        let table = self.table();
+        let names = table
+            .iter()
+            .find(|(c, _)| *c == category)
+            .or_else(|| table.iter().find(|(c, _)| *c == PluralCategory::Other))
+            .map_or(&table[0].1, |(_, names)| names);
+        let index = Unit::ALL.iter().position(|u| *u == unit).unwrap_or(0);
+        names[index]
+    }
+
+    /// Finds the unit by the long name or the abbreviation in the locale, like `минут` or `Std`.
+    /// The case and the count of whitespaces between the words are ignored.
+    ///
+    /// ```
+    /// use crate::ms_converter::{Locale, Unit};
+    ///
+    /// assert_eq!(Locale::Russian.unit_from_name("Минут"), Some(Unit::Minute));
+    /// assert_eq!(Locale::German.unit_from_name("Tagen"), Some(Unit::Day));
+    /// assert_eq!(Locale::French.unit_from_name("de  jours"), Some(Unit::Day));
+    /// assert_eq!(Locale::Spanish.unit_from_name("Tage"), None);
+    /// ```
+    pub fn unit_from_name(self, name: &str) -> Option<Unit> {
+        let names = self
+            .table()
+            .iter()
+            .flat_map(|(_, names)| names.iter().copied().zip(Unit::ALL.iter().copied()));
+        let aliases = self.aliases().iter().copied();
+        names
+            .chain(aliases)
+            .find(|(known, _)| eq_words(known, name))
+            .map(|(_, unit)| unit)
+    }
+
+    #[inline(always)]
+    #[doc(hidden)]
+    fn table(self) -> &'static [(PluralCategory, Names)] {
+        match self {
+            Locale::English => EN,
+            Locale::German => DE,
+            Locale::French => FR,
+            Locale::Spanish => ES,
+            Locale::Russian => RU,
+            Locale::Ukrainian => UK,
+            Locale::Polish => PL,
+            Locale::Arabic => AR,
+        }
+    }
+
+    /// Abbreviations and the other forms of the unit names, which are not used by the formatting,
+    /// like the genitive singular after the fractional counts in Slavic languages
+    #[inline(always)]
+    #[doc(hidden)]
+    fn aliases(self) -> &'static [(&'static str, Unit)] {
+        match self {
+            Locale::English => &[],
+            Locale::German => &[
+                ("Sek", Unit::Second),
+                ("Min", Unit::Minute),
+                ("Std", Unit::Hour),
+                ("Tagen", Unit::Day),
+                ("Jahren", Unit::Year),
+                ("Monaten", Unit::Month),
+                ("Mon", Unit::Month),
+            ],
+            Locale::French => &[
+                ("min", Unit::Minute),
+                ("j", Unit::Day),
+                ("sem", Unit::Week),
+                ("année", Unit::Year),
+                ("années", Unit::Year),
+            ],
+            Locale::Spanish => &[("seg", Unit::Second), ("min", Unit::Minute)],
+            Locale::Russian => &[
+                ("мкс", Unit::Microsecond),
+                ("мс", Unit::Millisecond),
+                ("с", Unit::Second),
+                ("сек", Unit::Second),
+                ("мин", Unit::Minute),
+                ("ч", Unit::Hour),
+                ("д", Unit::Day),
+                ("дн", Unit::Day),
+                ("сутки", Unit::Day),
+                ("суток", Unit::Day),
+                ("нед", Unit::Week),
+                ("мес", Unit::Month),
+                ("кв", Unit::Quarter),
+                ("г", Unit::Year),
+            ],
+            Locale::Ukrainian => &[
+                ("мкс", Unit::Microsecond),
+                ("мс", Unit::Millisecond),
+                ("с", Unit::Second),
+                ("сек", Unit::Second),
+                ("хв", Unit::Minute),
+                ("год", Unit::Hour),
+                ("д", Unit::Day),
+                ("дн", Unit::Day),
+                ("дня", Unit::Day),
+                ("тиж", Unit::Week),
+                ("тижня", Unit::Week),
+                ("міс", Unit::Month),
+                ("місяця", Unit::Month),
+                ("кв", Unit::Quarter),
+                ("р", Unit::Year),
+                ("року", Unit::Year),
+            ],
+            Locale::Polish => &[
+                ("sek", Unit::Second),
+                ("godz", Unit::Hour),
+                ("dnia", Unit::Day),
+                ("tyg", Unit::Week),
+                ("tygodnia", Unit::Week),
+                ("mies", Unit::Month),
+                ("miesiąca", Unit::Month),
+                ("kw", Unit::Quarter),
+                ("roku", Unit::Year),
+            ],
+            Locale::Arabic => &[
+                ("ثانية واحدة", Unit::Second),
+                ("ث", Unit::Second),
+                ("د", Unit::Minute),
+                ("س", Unit::Hour),
+                ("ي", Unit::Day),
+                ("سنة واحدة", Unit::Year),
+            ],
+        }
+    }
+}
+
+/// Compares the unit names ignoring the case and the count of whitespaces between the words
+#[inline(always)]
+#[doc(hidden)]
+fn eq_words(known: &str, name: &str) -> bool {
+    let mut words = name.split_whitespace();
+    known
+        .split(' ')
+        .all(|word| words.next().is_some_and(|w| eq_ignore_case(word, w)))
+        && words.next().is_none()
+}
+
+#[inline(always)]
+#[doc(hidden)]
+fn eq_ignore_case(a: &str, b: &str) -> bool {
+    a.chars()
+        .flat_map(char::to_lowercase)
+        .eq(b.chars().flat_map(char::to_lowercase))
+}
+
+const EN: &[(PluralCategory, Names)] = &[
+    (
+        PluralCategory::One,
+        [
+            "nanosecond",
+            "microsecond",
+            "millisecond",
+            "second",
+            "minute",
+            "hour",
+            "day",
+            "week",
+            "month",
+            "quarter",
+            "year",
+            "decade",
+            "century",
+        ],
+    ),
+    (
+        PluralCategory::Other,
+        [
+            "nanoseconds",
+            "microseconds",
+            "milliseconds",
+            "seconds",
+            "minutes",
+            "hours",
+            "days",
+            "weeks",
+            "months",
+            "quarters",
+            "years",
+            "decades",
+            "centuries",
+        ],
+    ),
+];
+
+const DE: &[(PluralCategory, Names)] = &[
+    (
+        PluralCategory::One,
+        [
+            "Nanosekunde",
+            "Mikrosekunde",
+            "Millisekunde",
+            "Sekunde",
+            "Minute",
+            "Stunde",
+            "Tag",
+            "Woche",
+            "Monat",
+            "Quartal",
+            "Jahr",
+            "Jahrzehnt",
+            "Jahrhundert",
+        ],
+    ),
+    (
+        PluralCategory::Other,
+        [
+            "Nanosekunden",
+            "Mikrosekunden",
+            "Millisekunden",
+            "Sekunden",
+            "Minuten",
+            "Stunden",
+            "Tage",
+            "Wochen",
+            "Monate",
+            "Quartale",
+            "Jahre",
+            "Jahrzehnte",
+            "Jahrhunderte",
+        ],
+    ),
+];
+
+const FR: &[(PluralCategory, Names)] = &[
+    (
+        PluralCategory::One,
+        [
+            "nanoseconde",
+            "microseconde",
+            "milliseconde",
+            "seconde",
+            "minute",
+            "heure",
+            "jour",
+            "semaine",
+            "mois",
+            "trimestre",
+            "an",
+            "décennie",
+            "siècle",
+        ],
+    ),
+    (
+        PluralCategory::Many,
+        [
+            "de nanosecondes",
+            "de microsecondes",
+            "de millisecondes",
+            "de secondes",
+            "de minutes",
+            "d’heures",
+            "de jours",
+            "de semaines",
+            "de mois",
+            "de trimestres",
+            "d’ans",
+            "de décennies",
+            "de siècles",
+        ],
+    ),
+    (
+        PluralCategory::Other,
+        [
+            "nanosecondes",
+            "microsecondes",
+            "millisecondes",
+            "secondes",
+            "minutes",
+            "heures",
+            "jours",
+            "semaines",
+            "mois",
+            "trimestres",
+            "ans",
+            "décennies",
+            "siècles",
+        ],
+    ),
+];
+
+const ES: &[(PluralCategory, Names)] = &[
+    (
+        PluralCategory::One,
+        [
+            "nanosegundo",
+            "microsegundo",
+            "milisegundo",
+            "segundo",
+            "minuto",
+            "hora",
+            "día",
+            "semana",
+            "mes",
+            "trimestre",
+            "año",
+            "década",
+            "siglo",
+        ],
+    ),
+    (
+        PluralCategory::Many,
+        [
+            "de nanosegundos",
+            "de microsegundos",
+            "de milisegundos",
+            "de segundos",
+            "de minutos",
+            "de horas",
+            "de días",
+            "de semanas",
+            "de meses",
+            "de trimestres",
+            "de años",
+            "de décadas",
+            "de siglos",
+        ],
+    ),
+    (
+        PluralCategory::Other,
+        [
+            "nanosegundos",
+            "microsegundos",
+            "milisegundos",
+            "segundos",
+            "minutos",
+            "horas",
+            "días",
+            "semanas",
+            "meses",
+            "trimestres",
+            "años",
+            "décadas",
+            "siglos",
+        ],
+    ),
+];
+
+const RU: &[(PluralCategory, Names)] = &[
+    (
+        PluralCategory::One,
+        [
+            "наносекунда",
+            "микросекунда",
+            "миллисекунда",
+            "секунда",
+            "минута",
+            "час",
+            "день",
+            "неделя",
+            "месяц",
+            "квартал",
+            "год",
+            "десятилетие",
+            "век",
+        ],
+    ),
+    (
+        PluralCategory::Few,
+        [
+            "наносекунды",
+            "микросекунды",
+            "миллисекунды",
+            "секунды",
+            "минуты",
+            "часа",
+            "дня",
+            "недели",
+            "месяца",
+            "квартала",
+            "года",
+            "десятилетия",
+            "века",
+        ],
+    ),
+    (
+        PluralCategory::Many,
+        [
+            "наносекунд",
+            "микросекунд",
+            "миллисекунд",
+            "секунд",
+            "минут",
+            "часов",
+            "дней",
+            "недель",
+            "месяцев",
+            "кварталов",
+            "лет",
+            "десятилетий",
+            "веков",
+        ],
+    ),
+];
+
+const UK: &[(PluralCategory, Names)] = &[
+    (
+        PluralCategory::One,
+        [
+            "наносекунда",
+            "мікросекунда",
+            "мілісекунда",
+            "секунда",
+            "хвилина",
+            "година",
+            "день",
+            "тиждень",
+            "місяць",
+            "квартал",
+            "рік",
+            "десятиліття",
+            "століття",
+        ],
+    ),
+    (
+        PluralCategory::Few,
+        [
+            "наносекунди",
+            "мікросекунди",
+            "мілісекунди",
+            "секунди",
+            "хвилини",
+            "години",
+            "дні",
+            "тижні",
+            "місяці",
+            "квартали",
+            "роки",
+            "десятиліття",
+            "століття",
+        ],
+    ),
+    (
+        PluralCategory::Many,
+        [
+            "наносекунд",
+            "мікросекунд",
+            "мілісекунд",
+            "секунд",
+            "хвилин",
+            "годин",
+            "днів",
+            "тижнів",
+            "місяців",
+            "кварталів",
+            "років",
+            "десятиліть",
+            "століть",
+        ],
+    ),
+];
+
+const PL: &[(PluralCategory, Names)] = &[
+    (
+        PluralCategory::One,
+        [
+            "nanosekunda",
+            "mikrosekunda",
+            "milisekunda",
+            "sekunda",
+            "minuta",
+            "godzina",
+            "dzień",
+            "tydzień",
+            "miesiąc",
+            "kwartał",
+            "rok",
+            "dekada",
+            "wiek",
+        ],
+    ),
+    (
+        PluralCategory::Few,
+        [
+            "nanosekundy",
+            "mikrosekundy",
+            "milisekundy",
+            "sekundy",
+            "minuty",
+            "godziny",
+            "dni",
+            "tygodnie",
+            "miesiące",
+            "kwartały",
+            "lata",
+            "dekady",
+            "wieki",
+        ],
+    ),
+    (
+        PluralCategory::Many,
+        [
+            "nanosekund",
+            "mikrosekund",
+            "milisekund",
+            "sekund",
+            "minut",
+            "godzin",
+            "dni",
+            "tygodni",
+            "miesięcy",
+            "kwartałów",
+            "lat",
+            "dekad",
+            "wieków",
+        ],
+    ),
+];
+
+const AR: &[(PluralCategory, Names)] = &[
+    (
+        PluralCategory::Two,
+        [
+            "نانو ثانيتان",
+            "ميكرو ثانيتان",
+            "ملي ثانيتان",
+            "ثانيتان",
+            "دقيقتان",
+            "ساعتان",
+            "يومان",
+            "أسبوعان",
+            "شهران",
+            "ربعا سنة",
+            "سنتان",
+            "عقدان",
+            "قرنان",
+        ],
+    ),
+    (
+        PluralCategory::Few,
+        [
+            "نانو ثانية",
+            "ميكرو ثانية",
+            "ملي ثانية",
+            "ثوانٍ",
+            "دقائق",
+            "ساعات",
+            "أيام",
+            "أسابيع",
+            "أشهر",
+            "أرباع سنة",
+            "سنوات",
+            "عقود",
+            "قرون",
+        ],
+    ),
+    (
+        PluralCategory::Many,
+        [
+            "نانو ثانية",
+            "ميكرو ثانية",
+            "ملي ثانية",
+            "ثانية",
+            "دقيقة",
+            "ساعة",
+            "يومًا",
+            "أسبوعًا",
+            "شهرًا",
+            "ربع سنة",
+            "سنة",
+            "عقدًا",
+            "قرنًا",
+        ],
+    ),
+    (
+        PluralCategory::Other,
+        [
+            "نانو ثانية",
+            "ميكرو ثانية",
+            "ملي ثانية",
+            "ثانية",
+            "دقيقة",
+            "ساعة",
+            "يوم",
+            "أسبوع",
+            "شهر",
+            "ربع سنة",
+            "سنة",
+            "عقد",
+            "قرن",
+        ],
+    ),
+];
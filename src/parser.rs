@@ -0,0 +1,234 @@
+use crate::constant::round_nanos;
+use crate::{get_nanos, parse_compound_nanos};
+use crate::{skip_whitespaces, to_ascii_lowercase, MAX_POSTFIX_LEN};
+use crate::{Error, ErrorKind, Locale, Unit};
+use core::convert::TryFrom;
+use core::ops::{Add, Div, Rem, Sub};
+use core::time::Duration;
+
+/// Options of the human-like times parsing.
+/// `Parser::new()` is strict and works exactly like `ms`, `ns` and `ns_into_time` functions.
+/// The lenient mode accepts the time strings, which users type into the forms:
+/// postfixes are case-insensitive, and any whitespaces are allowed around the sign,
+/// the values and the postfixes.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::Parser;
+///
+/// let parser = Parser::new().lenient(true);
+/// assert_eq!(parser.ms("10 Minutes").unwrap(), 600000);
+/// assert_eq!(parser.ms("5  s").unwrap(), 5000);
+/// assert_eq!(parser.ms("1H").unwrap(), 3600000);
+/// assert_eq!(parser.ms(" 3 days ").unwrap(), 259200000);
+/// assert_eq!(parser.ms("- 1 Hour  30 MIN").unwrap(), -5400000);
+/// ```
+///
+/// ```
+/// use crate::ms_converter::Parser;
+///
+/// let parser = Parser::new();
+/// assert!(parser.ms("10 Minutes").is_err());
+/// assert!(parser.ms("3 days ").is_err());
+/// ```
+///
+/// The parser with locales recognizes the unit names of the languages and their inflected forms,
+/// like `5 минут`, `3 Tage` or `2 horas`, besides the English postfixes.
+/// The unit names of the locales are case-insensitive.
+/// ```
+/// use crate::ms_converter::{ms, Locale, Parser};
+///
+/// let parser = Parser::new().locales(&[Locale::Russian, Locale::German, Locale::Spanish]);
+/// assert_eq!(parser.ms("5 минут").unwrap(), ms("5 minutes").unwrap());
+/// assert_eq!(parser.ms("3 Tage").unwrap(), ms("3 days").unwrap());
+/// assert_eq!(parser.ms("2 horas 30 min").unwrap(), ms("2h 30m").unwrap());
+/// assert!(Parser::new().ms("5 минут").is_err());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Parser {
+    pub(crate) lenient: bool,
+    /// Bit set of the locales
+    locales: u32,
+}
+
+impl Parser {
+    /// Creates the strict parser
+    pub const fn new() -> Parser {
+        Parser {
+            lenient: false,
+            locales: 0,
+        }
+    }
+
+    /// Ignores the case of postfixes and the whitespaces around the sign, the values and the postfixes
+    pub const fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Recognizes the unit names of the locale, the locales are added to the previous ones
+    pub const fn locale(mut self, locale: Locale) -> Self {
+        self.locales |= 1 << locale as u32;
+        self
+    }
+
+    /// Recognizes the unit names of every locale,
+    /// the same unit name of several locales means the unit of the first one in `Locale::ALL`
+    pub const fn locales(mut self, locales: &[Locale]) -> Self {
+        let mut i = 0;
+        while i < locales.len() {
+            self = self.locale(locales[i]);
+            i += 1;
+        }
+        self
+    }
+
+    /// Converts the human-like time into milliseconds like `ms` function
+    pub fn ms<T>(&self, s: T) -> Result<i64, Error>
+    where
+        T: AsRef<str>,
+    {
+        self.ms_by(s.as_ref(), get_nanos)
+    }
+
+    /// Converts the human-like time into nanoseconds like `ns` function
+    pub fn ns<T>(&self, s: T) -> Result<i64, Error>
+    where
+        T: AsRef<str>,
+    {
+        self.ns_by(s.as_ref(), get_nanos)
+    }
+
+    /// Converts the human-like time into `time.Duration` like `ms_into_time` function
+    pub fn ms_into_time<T>(&self, s: T) -> Result<Duration, Error>
+    where
+        T: AsRef<str>,
+    {
+        self.ms_into_time_by(s.as_ref(), get_nanos)
+    }
+
+    /// Converts the human-like time into `time.Duration` like `ns_into_time` function
+    pub fn ns_into_time<T>(&self, s: T) -> Result<Duration, Error>
+    where
+        T: AsRef<str>,
+    {
+        self.ns_into_time_by(s.as_ref(), get_nanos)
+    }
+
+    /// Converts the human-like time into milliseconds with the postfixes of the lookup function
+    #[inline(always)]
+    #[doc(hidden)]
+    pub(crate) fn ms_by<M>(&self, s: &str, lookup: M) -> Result<i64, Error>
+    where
+        M: Fn(&[u8]) -> Result<u64, Error>,
+    {
+        let (negative, nanos) = parse_compound_nanos(s.as_bytes(), *self, lookup)?;
+        round_nanos(negative, nanos).map_err(|kind| Error::from(kind).with_span(0, s.len()))
+    }
+
+    /// Converts the human-like time into nanoseconds with the postfixes of the lookup function
+    #[inline(always)]
+    #[doc(hidden)]
+    pub(crate) fn ns_by<M>(&self, s: &str, lookup: M) -> Result<i64, Error>
+    where
+        M: Fn(&[u8]) -> Result<u64, Error>,
+    {
+        match parse_compound_nanos(s.as_bytes(), *self, lookup)? {
+            (true, nanos) if nanos <= 1 << 63 => Ok((nanos as i64).wrapping_neg()),
+            (false, nanos) if nanos < 1 << 63 => Ok(nanos as i64),
+            _ => Err(ErrorKind::Overflow.into()),
+        }
+    }
+
+    /// Converts the human-like time into `time.Duration` with the postfixes of the lookup function
+    #[inline(always)]
+    #[doc(hidden)]
+    pub(crate) fn ms_into_time_by<M>(&self, s: &str, lookup: M) -> Result<Duration, Error>
+    where
+        M: Fn(&[u8]) -> Result<u64, Error>,
+    {
+        let milliseconds = self.ms_by(s, lookup)?;
+        if milliseconds < 0 {
+            return Err(ErrorKind::NegativeDuration.into());
+        }
+        Ok(Duration::from_millis(milliseconds as u64))
+    }
+
+    /// Converts the human-like time into `time.Duration` with nanoseconds precision
+    /// and the postfixes of the lookup function
+    #[inline(always)]
+    #[doc(hidden)]
+    pub(crate) fn ns_into_time_by<M>(&self, s: &str, lookup: M) -> Result<Duration, Error>
+    where
+        M: Fn(&[u8]) -> Result<u64, Error>,
+    {
+        match parse_compound_nanos(s.as_bytes(), *self, lookup)? {
+            (true, nanos) if nanos > 0 => Err(ErrorKind::NegativeDuration.into()),
+            (_, nanos) => {
+                let secs =
+                    u64::try_from(nanos.div(1_000_000_000)).map_err(|_| ErrorKind::Overflow)?;
+                Ok(Duration::new(secs, nanos.rem(1_000_000_000) as u32))
+            }
+        }
+    }
+
+    /// Finds the nanoseconds count of the postfix by the lookup function,
+    /// the lenient mode lowercases the postfix and the locales are used for the unknown postfixes
+    #[inline(always)]
+    #[doc(hidden)]
+    pub(crate) fn nanos<M>(&self, postfix: &[u8], lookup: &M) -> Result<u64, Error>
+    where
+        M: Fn(&[u8]) -> Result<u64, Error>,
+    {
+        let mut buffer = [0_u8; MAX_POSTFIX_LEN];
+        match self.lenient {
+            true => lookup(to_ascii_lowercase(postfix, &mut buffer)),
+            false => lookup(postfix),
+        }
+        .or_else(|e| {
+            // The unit names of the locales are resolved by the postfixes of the lookup function,
+            // so the units, which are defined or removed by `UnitRegistry`, are used.
+            self.localized_unit(postfix)
+                .and_then(|unit| {
+                    unit.postfixes()
+                        .iter()
+                        .find_map(|name| lookup(name.as_bytes()).ok())
+                })
+                .ok_or(e)
+        })
+    }
+
+    /// Finds the unit by the unit name of the parser locales
+    #[inline(always)]
+    #[doc(hidden)]
+    pub(crate) fn localized_unit(&self, postfix: &[u8]) -> Option<Unit> {
+        let name = core::str::from_utf8(postfix).ok()?;
+        Locale::ALL
+            .iter()
+            .filter(|locale| self.locales & 1 << **locale as u32 != 0)
+            .find_map(|locale| locale.unit_from_name(name))
+    }
+
+    /// Returns the length of the two words unit name of the parser locales,
+    /// if the string starts with it and the first word has the length of the postfix
+    #[inline(always)]
+    #[doc(hidden)]
+    pub(crate) fn join_words(&self, s: &[u8], postfix: usize) -> Option<usize> {
+        if self.locales == 0 || postfix == 0 {
+            return None;
+        }
+        let rest = match s[postfix..].split_first() {
+            Some((c, rest)) if c.is_ascii_whitespace() => skip_whitespaces(rest, self.lenient),
+            _ => return None,
+        };
+        let word = rest
+            .iter()
+            .position(|b| ms_converter_core::is_separator(*b))
+            .unwrap_or(rest.len());
+        let len = s.len().sub(rest.len()).add(word);
+        match word > 0 && self.localized_unit(&s[..len]).is_some() {
+            true => Some(len),
+            false => None,
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{Error, ErrorKind, Rounding, Unit};
+use core::fmt::{self, Display, Formatter, Write};
+use core::ops::{Div, Mul, Rem, Sub};
+
+/// How many decimal places the fractional formatters write, at most 9 places are supported.
+///
+/// ### Usage
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use crate::ms_converter::{get_duration_by_postfix_fractional, Precision};
+///
+/// let value = get_duration_by_postfix_fractional(5_400_000, "h", Precision::Max(2)).unwrap();
+/// assert_eq!(value, "1.5h");
+///
+/// let value = get_duration_by_postfix_fractional(5_400_000, "h", Precision::Fixed(2)).unwrap();
+/// assert_eq!(value, "1.50h")
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Precision {
+    /// Exactly this number of decimal places, like `1.50h` for two places
+    Fixed(u8),
+    /// Up to this number of decimal places without trailing zeros, like `1.5h` for two places
+    Max(u8),
+}
+
+impl Precision {
+    /// Maximum supported number of decimal places
+    pub const MAX_DIGITS: u8 = 9;
+
+    /// Divides the milliseconds count by the unit and keeps the decimal places,
+    /// the last place is rounded half away from zero
+    #[inline(always)]
+    #[doc(hidden)]
+    pub(crate) fn divide_millis(self, milliseconds: i64, unit: Unit) -> Result<Decimal, Error> {
+        let (mut digits, trim) = match self {
+            Precision::Fixed(digits) => (digits.min(Precision::MAX_DIGITS), false),
+            Precision::Max(digits) => (digits.min(Precision::MAX_DIGITS), true),
+        };
+        let scale = 10_i128.pow(u32::from(digits));
+        let nanos = i128::from(unit.as_nanos());
+        let value = Rounding::HalfAwayFromZero
+            .divide(i128::from(milliseconds).mul(1_000_000).mul(scale), nanos);
+        let integer = value.div(scale).unsigned_abs();
+        let limit = match value < 0 {
+            true => i64::MIN.unsigned_abs(),
+            false => i64::MAX.unsigned_abs(),
+        };
+        if integer > u128::from(limit) {
+            return Err(ErrorKind::Overflow.into());
+        }
+
+        let mut fraction = value.rem(scale).unsigned_abs() as u64;
+        while trim && digits > 0 && fraction.rem(10) == 0 {
+            fraction = fraction.div(10);
+            digits = digits.sub(1);
+        }
+        Ok(Decimal {
+            negative: value < 0,
+            integer: integer as u64,
+            fraction,
+            digits,
+        })
+    }
+}
+
+/// Decimal number, which is written by the fractional formatters
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub(crate) struct Decimal {
+    negative: bool,
+    integer: u64,
+    fraction: u64,
+    digits: u8,
+}
+
+impl Decimal {
+    /// Returns `true` if the number is written as `1` or `-1`, so the postfix is singular
+    #[inline(always)]
+    #[doc(hidden)]
+    pub(crate) fn is_one(&self) -> bool {
+        self.integer == 1 && self.digits == 0
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            f.write_char('-')?;
+        }
+        write!(f, "{}", self.integer)?;
+        if self.digits > 0 {
+            write!(
+                f,
+                ".{:0width$}",
+                self.fraction,
+                width = usize::from(self.digits)
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,366 @@
+use crate::UNITS_COUNT;
+use crate::{error, Error, ErrorKind, Parser, Rounding, Unit};
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use core::time::Duration;
+
+/// Units of the human-like times, which are known by the parsing and the formatting.
+/// `UnitRegistry::new()` knows the same postfixes as `ms` function and formats the time
+/// like `get_max_possible_duration` and `get_max_possible_duration_long` functions.
+/// You can add your own units and aliases, or remove the ambiguous ones.
+///
+/// The added units are only parsed, the formatting uses the built-in units from milliseconds to days,
+/// which are written with their registered names, so the output is always parsed back by the registry.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::UnitRegistry;
+///
+/// let registry = UnitRegistry::new()
+///     .add_unit("sprint", "2w")?
+///     .add_unit("shift", "8h")?
+///     .add_unit("tick", "50ms")?
+///     .add_alias("sprints", "sprint")?
+///     .add_alias("fortnight", "sprint")?;
+///
+/// assert_eq!(registry.ms("2 sprints")?, 2 * 14 * 86_400_000);
+/// assert_eq!(registry.ms("1 shift 30m")?, 30_600_000);
+/// assert_eq!(registry.ms("3 tick")?, 150);
+/// assert_eq!(registry.ms("1 fortnight")?, registry.ms("1 sprint")?);
+/// # Ok::<(), ms_converter::Error>(())
+/// ```
+///
+/// Remove the postfixes, which you consider ambiguous, and the formatting uses the other names:
+/// ```
+/// use crate::ms_converter::{ErrorKind, UnitRegistry};
+///
+/// let registry = UnitRegistry::new().remove("m")?;
+///
+/// assert_eq!(registry.ms("5 m").unwrap_err().kind(), ErrorKind::UnknownUnit);
+/// assert_eq!(registry.ms("5 min")?, 300_000);
+/// assert_eq!(registry.format(300_000)?, "5min");
+/// # Ok::<(), ms_converter::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnitRegistry {
+    parser: Parser,
+    /// Every name with its nanoseconds count and the built-in unit
+    names: Vec<(Cow<'static, str>, u64, Option<Unit>)>,
+}
+
+impl UnitRegistry {
+    /// Creates the registry with the built-in units, which works exactly like `ms` function
+    pub fn new() -> UnitRegistry {
+        let names = ms_converter_core::postfixes()
+            // The empty postfix and "μs" with the greek mu, which are not suggested in errors.
+            .chain(["", "\u{3bc}s"])
+            .filter_map(|name| {
+                Unit::from_postfix(name).map(|unit| (name.into(), unit.as_nanos(), Some(unit)))
+            })
+            .collect();
+        UnitRegistry {
+            parser: Parser::new(),
+            names,
+        }
+    }
+
+    /// Uses the options of the parser, like the lenient mode or the locales.
+    /// The unit names of the locales follow the registry, like `Monat` after `define("month", "30d")`,
+    /// and they are unknown, when every built-in postfix of their unit is removed.
+    pub fn parser(mut self, parser: Parser) -> Self {
+        self.parser = parser;
+        self
+    }
+
+    /// Adds the unit, which is defined by the time string, like `2w` or `1 sprint`.
+    /// The name can't have whitespaces, digits, dots and signs, and must be unknown by the registry.
+    /// The unit with the same time as a built-in unit, like `60s`, is the alias of the built-in one.
+    /// The registry can have up to 32 different units, the next one is `ErrorKind::TooManyUnits` error.
+    /// The name can be borrowed or owned, like the names from a config file.
+    ///
+    /// ```
+    /// use crate::ms_converter::UnitRegistry;
+    ///
+    /// let registry = UnitRegistry::new().add_unit("shift", "8h").unwrap();
+    /// assert_eq!(registry.ms("2 shift").unwrap(), 57_600_000);
+    /// assert!(UnitRegistry::new().add_unit("shift", "-8h").is_err());
+    ///
+    /// let name = String::from("sprint");
+    /// let registry = UnitRegistry::new().add_unit(name, "2w").unwrap();
+    /// assert_eq!(registry.ms("1 sprint").unwrap(), 1_209_600_000);
+    /// ```
+    pub fn add_unit<N>(mut self, name: N, definition: &str) -> Result<Self, Error>
+    where
+        N: Into<Cow<'static, str>>,
+    {
+        let name = name.into();
+        self.check_name(&name)?;
+        let nanos = self.definition_nanos(definition)?;
+        let unit = Unit::from_nanos(nanos);
+        self.names.push((name, nanos, unit));
+        self.check_units_count()?;
+        Ok(self)
+    }
+
+    /// Adds the alias of the known name, like `sprints` for `sprint`.
+    /// The alias can't have whitespaces, digits, dots and signs, and must be unknown by the registry.
+    ///
+    /// ```
+    /// use crate::ms_converter::UnitRegistry;
+    ///
+    /// let registry = UnitRegistry::new().add_alias("minuto", "m").unwrap();
+    /// assert_eq!(registry.ms("5 minuto").unwrap(), 300_000);
+    /// assert!(UnitRegistry::new().add_alias("minuto", "mni").is_err());
+    /// ```
+    pub fn add_alias<A>(mut self, alias: A, name: &str) -> Result<Self, Error>
+    where
+        A: Into<Cow<'static, str>>,
+    {
+        let alias = alias.into();
+        self.check_name(&alias)?;
+        let (_, nanos, unit) = self.find(name.as_bytes())?;
+        let (nanos, unit) = (*nanos, *unit);
+        self.names.push((alias, nanos, unit));
+        Ok(self)
+    }
+
+    /// Changes the definition of the unit with all its names, like a month of 30 days instead of
+    /// the twelfth of the year. The definition is the time string like in `add_unit` method.
+    ///
+    /// ```
+    /// use crate::ms_converter::{UnitRegistry, DAY};
+    ///
+    /// let registry = UnitRegistry::new().define("month", "30d").unwrap();
+    /// assert_eq!(registry.ms("1 mo").unwrap(), 30 * DAY as i64);
+    /// assert_eq!(registry.ms("2 months").unwrap(), 60 * DAY as i64);
+    /// assert!(UnitRegistry::new().define("month", "0d").is_err());
+    /// ```
+    pub fn define(mut self, name: &str, definition: &str) -> Result<Self, Error> {
+        let (_, old_nanos, old_unit) = self.find(name.as_bytes())?;
+        let (old_nanos, old_unit) = (*old_nanos, *old_unit);
+        let nanos = self.definition_nanos(definition)?;
+        let unit = Unit::from_nanos(nanos);
+        self.names
+            .iter_mut()
+            .filter(|(_, n, u)| *n == old_nanos && *u == old_unit)
+            .for_each(|(_, n, u)| {
+                *n = nanos;
+                *u = unit;
+            });
+        self.check_units_count()?;
+        Ok(self)
+    }
+
+    /// Removes the name, the unit is removed with its last name
+    ///
+    /// ```
+    /// use crate::ms_converter::UnitRegistry;
+    ///
+    /// let registry = UnitRegistry::new().remove("m").unwrap();
+    /// assert!(!registry.contains("m"));
+    /// assert!(registry.contains("min"));
+    /// assert!(registry.remove("m").is_err());
+    /// ```
+    pub fn remove(mut self, name: &str) -> Result<Self, Error> {
+        self.find(name.as_bytes())?;
+        self.names.retain(|(known, _, _)| known != name);
+        Ok(self)
+    }
+
+    /// Checks that the name is known by the registry
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.iter().any(|(known, _, _)| known == name)
+    }
+
+    /// Converts the human-like time into milliseconds like `ms` function
+    pub fn ms<T>(&self, s: T) -> Result<i64, Error>
+    where
+        T: AsRef<str>,
+    {
+        self.parser.ms_by(s.as_ref(), |postfix| self.nanos(postfix))
+    }
+
+    /// Converts the human-like time into nanoseconds like `ns` function
+    pub fn ns<T>(&self, s: T) -> Result<i64, Error>
+    where
+        T: AsRef<str>,
+    {
+        self.parser.ns_by(s.as_ref(), |postfix| self.nanos(postfix))
+    }
+
+    /// Converts the human-like time into `time.Duration` like `ms_into_time` function
+    pub fn ms_into_time<T>(&self, s: T) -> Result<Duration, Error>
+    where
+        T: AsRef<str>,
+    {
+        self.parser
+            .ms_into_time_by(s.as_ref(), |postfix| self.nanos(postfix))
+    }
+
+    /// Converts the human-like time into `time.Duration` like `ns_into_time` function
+    pub fn ns_into_time<T>(&self, s: T) -> Result<Duration, Error>
+    where
+        T: AsRef<str>,
+    {
+        self.parser
+            .ns_into_time_by(s.as_ref(), |postfix| self.nanos(postfix))
+    }
+
+    /// Getting human-like time from milliseconds like `get_max_possible_duration` function
+    /// with the short names of the registry.
+    /// The time is written in the smaller unit, if the unit has no names.
+    pub fn format(&self, milliseconds: i64) -> Result<String, Error> {
+        let mut s = String::new();
+        self.write(&mut s, milliseconds)?;
+        Ok(s)
+    }
+
+    /// Getting long human-like time from milliseconds like `get_max_possible_duration_long` function
+    /// with the long names of the registry.
+    /// The short name is used, if the unit has no long names.
+    pub fn format_long(&self, milliseconds: i64) -> Result<String, Error> {
+        let mut s = String::new();
+        self.write_long(&mut s, milliseconds)?;
+        Ok(s)
+    }
+
+    /// Writes the milliseconds count like `format` method into `fmt::Write`
+    pub fn write<W: Write>(&self, w: &mut W, milliseconds: i64) -> Result<(), Error> {
+        let (value, unit) = self.get_max_possible_unit(milliseconds)?;
+        let name = self.short_name(unit).ok_or(ErrorKind::UnknownUnit)?;
+        write!(w, "{}{}", value, name).map_err(|_| ErrorKind::Format.into())
+    }
+
+    /// Writes the milliseconds count like `format_long` method into `fmt::Write`
+    pub fn write_long<W: Write>(&self, w: &mut W, milliseconds: i64) -> Result<(), Error> {
+        let (value, unit) = self.get_max_possible_unit(milliseconds)?;
+        let name = self
+            .long_name(unit, value.unsigned_abs())
+            .ok_or(ErrorKind::UnknownUnit)?;
+        write!(w, "{} {}", value, name).map_err(|_| ErrorKind::Format.into())
+    }
+
+    #[inline(always)]
+    #[doc(hidden)]
+    fn check_name(&self, name: &str) -> Result<(), Error> {
+        if name.bytes().any(ms_converter_core::is_separator) {
+            return Err(Error::from(ErrorKind::InvalidSyntax).with_span(0, name.len()));
+        }
+        if self.contains(name) {
+            return Err(Error::from(ErrorKind::RepeatedUnit).with_span(0, name.len()));
+        }
+        Ok(())
+    }
+
+    /// Parses the positive definition of the unit into nanoseconds
+    #[inline(always)]
+    #[doc(hidden)]
+    fn definition_nanos(&self, definition: &str) -> Result<u64, Error> {
+        match self.ns(definition)? {
+            nanos if nanos > 0 => Ok(nanos as u64),
+            0 => Err(ErrorKind::InvalidNumber.into()),
+            _ => Err(ErrorKind::NegativeDuration.into()),
+        }
+    }
+
+    /// Checks that the units are not more than one time string can have
+    #[inline(always)]
+    #[doc(hidden)]
+    fn check_units_count(&self) -> Result<(), Error> {
+        let mut units = self
+            .names
+            .iter()
+            .map(|(_, nanos, _)| *nanos)
+            .collect::<Vec<_>>();
+        units.sort_unstable();
+        units.dedup();
+        match units.len() > UNITS_COUNT {
+            true => Err(ErrorKind::TooManyUnits.into()),
+            false => Ok(()),
+        }
+    }
+
+    #[inline(always)]
+    #[doc(hidden)]
+    fn find(&self, postfix: &[u8]) -> Result<&(Cow<'static, str>, u64, Option<Unit>), Error> {
+        self.names
+            .iter()
+            .find(|(name, _, _)| name.as_bytes() == postfix)
+            .ok_or_else(|| {
+                // The errors keep only the static suggestions, so the owned names aren't suggested.
+                let names = self.names.iter().filter_map(|(name, _, _)| match name {
+                    Cow::Borrowed(name) => Some(*name),
+                    Cow::Owned(_) => None,
+                });
+                Error::from(ErrorKind::UnknownUnit)
+                    .with_suggestion(error::closest_postfix(postfix, names))
+            })
+    }
+
+    /// Returns the nanoseconds count of the postfix like `get_nanos` function
+    #[inline(always)]
+    #[doc(hidden)]
+    fn nanos(&self, postfix: &[u8]) -> Result<u64, Error> {
+        self.find(postfix).map(|(_, nanos, _)| *nanos)
+    }
+
+    /// Finds the largest named unit up to a day like `get_max_possible_unit` function
+    /// and returns the rounded value in this unit
+    #[inline(always)]
+    #[doc(hidden)]
+    fn get_max_possible_unit(&self, milliseconds: i64) -> Result<(i64, Unit), Error> {
+        let m = milliseconds.unsigned_abs();
+        let mut units = Unit::ALL
+            .iter()
+            .filter(|unit| **unit >= Unit::Millisecond && **unit <= Unit::Day)
+            .filter(|unit| self.short_name(**unit).is_some());
+        let unit = *units
+            .clone()
+            .rev()
+            .find(|unit| m >= unit.whole_millis())
+            .or_else(|| units.next())
+            .ok_or(ErrorKind::UnknownUnit)?;
+        let value = Rounding::HalfAwayFromZero.divide_millis(milliseconds, unit)?;
+        Ok((value, unit))
+    }
+
+    /// The built-in short name of the unit, if it's registered, or the shortest name of the unit
+    #[inline(always)]
+    #[doc(hidden)]
+    fn short_name(&self, unit: Unit) -> Option<&str> {
+        let names = self
+            .names
+            .iter()
+            .filter(|(_, _, u)| *u == Some(unit))
+            .map(|(name, _, _)| name.as_ref());
+        names
+            .clone()
+            .find(|name| *name == unit.short_name())
+            .or_else(|| names.min_by_key(|name| name.len()))
+    }
+
+    /// The built-in long name of the unit for the count, if it's registered,
+    /// or the other long name or the short name of the unit
+    #[inline(always)]
+    #[doc(hidden)]
+    fn long_name(&self, unit: Unit, count: u64) -> Option<&str> {
+        let registered = |name: &&str| {
+            self.names
+                .iter()
+                .any(|(n, nanos, u)| n == name && *nanos == unit.as_nanos() && *u == Some(unit))
+        };
+        [unit.long_name(count), unit.long_name(1), unit.long_name(2)]
+            .iter()
+            .copied()
+            .find(registered)
+            .or_else(|| self.short_name(unit))
+    }
+}
+
+impl Default for UnitRegistry {
+    fn default() -> Self {
+        UnitRegistry::new()
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{Error, ErrorKind, Unit};
+use core::convert::TryFrom;
+use core::ops::{Add, Div, Mul, Rem};
+
+/// Rounding mode of the formatters, which is used when the time doesn't fit into the whole number of units.
+///
+/// ### Usage
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use crate::ms_converter::{get_duration_by_postfix_rounded, Rounding};
+///
+/// let value = get_duration_by_postfix_rounded(1500, "s", Rounding::HalfAwayFromZero).unwrap();
+/// assert_eq!(value, "2s");
+///
+/// let value = get_duration_by_postfix_rounded(1999, "s", Rounding::Floor).unwrap();
+/// assert_eq!(value, "1s");
+///
+/// let value = get_duration_by_postfix_rounded(1001, "s", Rounding::Ceil).unwrap();
+/// assert_eq!(value, "2s")
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Rounds to the nearest number and half-way cases away from zero, like `f64::round` does.
+    /// `1.5s` becomes `2s` and `-1.5s` becomes `-2s`.
+    #[default]
+    HalfAwayFromZero,
+    /// Rounds to the nearest number and half-way cases to the even one.
+    /// `1.5s` and `2.5s` become `2s`.
+    HalfEven,
+    /// Rounds towards negative infinity, the time is never overstated.
+    /// `1.9s` becomes `1s` and `-1.1s` becomes `-2s`.
+    Floor,
+    /// Rounds towards positive infinity, the time is never understated.
+    /// `1.1s` becomes `2s` and `-1.9s` becomes `-1s`.
+    Ceil,
+    /// Rounds towards zero, the fractional part is dropped.
+    /// `1.9s` becomes `1s` and `-1.9s` becomes `-1s`.
+    Truncate,
+}
+
+impl Rounding {
+    /// Divides the numerator by the positive denominator with the rounding mode
+    #[inline(always)]
+    #[doc(hidden)]
+    pub(crate) fn divide(self, numerator: i128, denominator: i128) -> i128 {
+        let quotient = numerator.div(denominator);
+        let remainder = numerator.rem(denominator);
+        let step = numerator.signum();
+        let away = match self {
+            Rounding::HalfAwayFromZero => remainder.abs().mul(2) >= denominator,
+            Rounding::HalfEven => match remainder.abs().mul(2).cmp(&denominator) {
+                core::cmp::Ordering::Greater => true,
+                core::cmp::Ordering::Equal => quotient.rem(2) != 0,
+                core::cmp::Ordering::Less => false,
+            },
+            Rounding::Floor => remainder < 0,
+            Rounding::Ceil => remainder > 0,
+            Rounding::Truncate => false,
+        };
+        match away {
+            true => quotient.add(step),
+            false => quotient,
+        }
+    }
+
+    /// Divides the milliseconds count by the unit with the rounding mode in the integer nanoseconds
+    #[inline(always)]
+    #[doc(hidden)]
+    pub(crate) fn divide_millis(self, milliseconds: i64, unit: Unit) -> Result<i64, Error> {
+        let nanos = i128::from(unit.as_nanos());
+        let value = self.divide(i128::from(milliseconds).mul(1_000_000), nanos);
+        i64::try_from(value).map_err(|_| ErrorKind::Overflow.into())
+    }
+}
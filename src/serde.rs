@@ -0,0 +1,346 @@
+/*!
+Serde support for the human-like times, enabled by `serde` feature.
+
+Every module can be used with `#[serde(with = "...")]` attribute.
+Values are deserialized from a number of milliseconds or from a human-like time string parsed by `ms`,
+`time.Duration` is parsed by `ns_into_time`, so it keeps the nanoseconds of the string.
+Values are serialized as a number of milliseconds, or as a compound human-like time string
+with `short` (like `get_compound_duration`) and `long` (like `CompoundFormat` with the long names) submodules.
+Human-like strings are parsed back into the same milliseconds count.
+Binary formats, like `bincode`, aren't self-describing,
+so they are deserialized only from the same type, which the module serializes.
+
+* `duration` for `time.Duration` fields
+* `millis` for `i64` fields with milliseconds
+* `option_duration` for `Option<time.Duration>` fields
+
+### Usage
+```
+use serde::{Deserialize, Serialize};
+use core::time::Duration;
+
+#[derive(Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "ms_converter::serde::duration")]
+    timeout: Duration,
+    #[serde(with = "ms_converter::serde::millis::long")]
+    interval: i64,
+    #[serde(default, with = "ms_converter::serde::option_duration::short")]
+    ttl: Option<Duration>,
+}
+
+let config: Config =
+    serde_json::from_str(r#"{"timeout": "1h 30m", "interval": 90000, "ttl": "2d"}"#).unwrap();
+assert_eq!(config.timeout, Duration::from_secs(5400));
+assert_eq!(config.interval, 90000);
+assert_eq!(config.ttl, Some(Duration::from_secs(172800)));
+
+let json = serde_json::to_string(&config).unwrap();
+assert_eq!(json, r#"{"timeout":5400000,"interval":"1 minute 30 seconds","ttl":"2d"}"#);
+```
+*/
+
+use crate::{float, ms, ns_into_time, CompoundFormat, ErrorKind};
+use ::serde::de::{self, Visitor};
+use ::serde::ser::Error as _;
+use ::serde::{Deserializer, Serialize, Serializer};
+use core::convert::TryFrom;
+use core::fmt::{self, Display, Formatter};
+use core::time::Duration;
+
+#[derive(Clone, Copy)]
+#[doc(hidden)]
+enum Style {
+    Millis,
+    Short,
+    Long,
+}
+
+#[doc(hidden)]
+struct Serialized(i64, Style);
+
+impl Serialize for Serialized {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.1 {
+            Style::Millis => serializer.serialize_i64(self.0),
+            Style::Short | Style::Long => serializer.collect_str(self),
+        }
+    }
+}
+
+/// Compound time string of the string styles, which `ms` parses back into the same value
+impl Display for Serialized {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        CompoundFormat::new()
+            .long(matches!(self.1, Style::Long))
+            .write(f, self.0)
+            .map_err(|_| fmt::Error)
+    }
+}
+
+#[doc(hidden)]
+struct MillisVisitor;
+
+impl<'de> Visitor<'de> for MillisVisitor {
+    type Value = i64;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("a number of milliseconds or a human-like time string")
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<i64, E> {
+        Ok(v)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<i64, E> {
+        i64::try_from(v).map_err(|_| E::custom(crate::Error::from(ErrorKind::Overflow)))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<i64, E> {
+        round_to_i64(v).map_err(E::custom)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<i64, E> {
+        ms(v).map_err(E::custom)
+    }
+}
+
+/// Numbers are milliseconds like `MillisVisitor` reads, strings are parsed by `ns_into_time`
+#[doc(hidden)]
+struct DurationVisitor;
+
+impl<'de> Visitor<'de> for DurationVisitor {
+    type Value = Duration;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("a non-negative number of milliseconds or a human-like time string")
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Duration, E> {
+        millis_into_time(v)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Duration, E> {
+        Ok(Duration::from_millis(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Duration, E> {
+        millis_into_time(round_to_i64(v).map_err(E::custom)?)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Duration, E> {
+        ns_into_time(v).map_err(E::custom)
+    }
+}
+
+#[doc(hidden)]
+struct OptionVisitor(Style);
+
+impl<'de> Visitor<'de> for OptionVisitor {
+    type Value = Option<Duration>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("an optional number of milliseconds or a human-like time string")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Option<Duration>, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Option<Duration>, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        deserialize_duration(deserializer, self.0).map(Some)
+    }
+}
+
+#[inline(always)]
+#[doc(hidden)]
+fn serialize_millis<S: Serializer>(
+    value: &i64,
+    style: Style,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    Serialized(*value, style).serialize(serializer)
+}
+
+/// Human-readable formats accept both numbers and strings,
+/// the binary formats can't be self-describing, so they read the serialized type of the style
+#[inline(always)]
+#[doc(hidden)]
+fn deserialize_millis<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    style: Style,
+) -> Result<i64, D::Error> {
+    match (deserializer.is_human_readable(), style) {
+        (true, _) => deserializer.deserialize_any(MillisVisitor),
+        (false, Style::Millis) => deserializer.deserialize_i64(MillisVisitor),
+        (false, Style::Short) | (false, Style::Long) => deserializer.deserialize_str(MillisVisitor),
+    }
+}
+
+#[inline(always)]
+#[doc(hidden)]
+fn serialize_duration<S: Serializer>(
+    value: &Duration,
+    style: Style,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let milliseconds = i64::try_from(value.as_millis())
+        .map_err(|_| S::Error::custom(crate::Error::from(ErrorKind::Overflow)))?;
+    Serialized(milliseconds, style).serialize(serializer)
+}
+
+#[inline(always)]
+#[doc(hidden)]
+fn deserialize_duration<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    style: Style,
+) -> Result<Duration, D::Error> {
+    match (deserializer.is_human_readable(), style) {
+        (true, _) => deserializer.deserialize_any(DurationVisitor),
+        (false, Style::Millis) => deserializer.deserialize_i64(DurationVisitor),
+        (false, Style::Short) | (false, Style::Long) => {
+            deserializer.deserialize_str(DurationVisitor)
+        }
+    }
+}
+
+#[inline(always)]
+#[doc(hidden)]
+fn serialize_option_duration<S: Serializer>(
+    value: &Option<Duration>,
+    style: Style,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => {
+            let milliseconds = i64::try_from(value.as_millis())
+                .map_err(|_| S::Error::custom(crate::Error::from(ErrorKind::Overflow)))?;
+            serializer.serialize_some(&Serialized(milliseconds, style))
+        }
+        None => serializer.serialize_none(),
+    }
+}
+
+#[inline(always)]
+#[doc(hidden)]
+fn deserialize_option_duration<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    style: Style,
+) -> Result<Option<Duration>, D::Error> {
+    deserializer.deserialize_option(OptionVisitor(style))
+}
+
+/// Converts the milliseconds count into `time.Duration`, the negative count is an error
+#[inline(always)]
+#[doc(hidden)]
+fn millis_into_time<E: de::Error>(milliseconds: i64) -> Result<Duration, E> {
+    match u64::try_from(milliseconds) {
+        Ok(milliseconds) => Ok(Duration::from_millis(milliseconds)),
+        Err(_) => Err(E::custom(crate::Error::from(ErrorKind::NegativeDuration))),
+    }
+}
+
+/// Rounds the value into `i64` and checks that it fits.
+#[inline(always)]
+#[doc(hidden)]
+fn round_to_i64(value: f64) -> Result<i64, crate::Error> {
+    match float::round(value) {
+        v if v >= i64::MIN as f64 && v < i64::MAX as f64 => Ok(v as i64),
+        _ => Err(ErrorKind::Overflow.into()),
+    }
+}
+
+macro_rules! serde_with {
+    ($type:ty, $serialize:ident, $deserialize:ident, $style:ident) => {
+        use ::serde::{Deserializer, Serializer};
+
+        #[allow(missing_docs)]
+        pub fn serialize<S: Serializer>(value: &$type, serializer: S) -> Result<S::Ok, S::Error> {
+            crate::serde::$serialize(value, crate::serde::Style::$style, serializer)
+        }
+
+        #[allow(missing_docs)]
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<$type, D::Error> {
+            crate::serde::$deserialize(deserializer, crate::serde::Style::$style)
+        }
+    };
+}
+
+/// `time.Duration` serialized as a number of milliseconds.
+pub mod duration {
+    use core::time::Duration;
+
+    serde_with!(Duration, serialize_duration, deserialize_duration, Millis);
+
+    /// `time.Duration` serialized like `get_compound_duration`, e.g. `"1h 30m"`.
+    pub mod short {
+        use core::time::Duration;
+
+        serde_with!(Duration, serialize_duration, deserialize_duration, Short);
+    }
+
+    /// `time.Duration` serialized with the long names, e.g. `"1 hour 30 minutes"`.
+    pub mod long {
+        use core::time::Duration;
+
+        serde_with!(Duration, serialize_duration, deserialize_duration, Long);
+    }
+}
+
+/// `i64` milliseconds serialized as a number.
+pub mod millis {
+    serde_with!(i64, serialize_millis, deserialize_millis, Millis);
+
+    /// `i64` milliseconds serialized like `get_compound_duration`, e.g. `"1h 30m"`.
+    pub mod short {
+        serde_with!(i64, serialize_millis, deserialize_millis, Short);
+    }
+
+    /// `i64` milliseconds serialized with the long names, e.g. `"1 hour 30 minutes"`.
+    pub mod long {
+        serde_with!(i64, serialize_millis, deserialize_millis, Long);
+    }
+}
+
+/// `Option<time.Duration>` serialized as a number of milliseconds or `null`.
+pub mod option_duration {
+    use core::time::Duration;
+
+    serde_with!(
+        Option<Duration>,
+        serialize_option_duration,
+        deserialize_option_duration,
+        Millis
+    );
+
+    /// `Option<time.Duration>` serialized like `get_compound_duration`, e.g. `"1h 30m"`.
+    pub mod short {
+        use core::time::Duration;
+
+        serde_with!(
+            Option<Duration>,
+            serialize_option_duration,
+            deserialize_option_duration,
+            Short
+        );
+    }
+
+    /// `Option<time.Duration>` serialized with the long names, e.g. `"1 hour 30 minutes"`.
+    pub mod long {
+        use core::time::Duration;
+
+        serde_with!(
+            Option<Duration>,
+            serialize_option_duration,
+            deserialize_option_duration,
+            Long
+        );
+    }
+}
@@ -1,8 +1,18 @@
 use crate::{
-    get_duration_by_postfix, get_max_possible_duration, get_max_possible_duration_long, ms,
-    ms_into_time, parse, DAY, HOUR, MINUTE, SECOND, WEEK, YEAR,
+    const_ms, fmt, get_compound_duration, get_duration_by_postfix,
+    get_duration_by_postfix_fractional, get_duration_by_postfix_rounded, get_go_duration,
+    get_iso_duration, get_max_possible_duration, get_max_possible_duration_fractional,
+    get_max_possible_duration_long, get_max_possible_duration_long_fractional,
+    get_max_possible_duration_long_localized, get_max_possible_duration_long_rounded,
+    get_max_possible_duration_long_up_to, get_max_possible_duration_rounded,
+    get_max_possible_duration_up_to, ms, ms_from_iso, ms_into_time, ns, ns_from_go, ns_into_time,
+    CompoundFormat, ErrorKind, HumanDuration, Locale, Parser, PluralCategory, Precision, Rounding,
+    Unit, UnitRegistry, CENTURY, DAY, DECADE, HOUR, MICROSECOND, MINUTE, MONTH, NANOSECOND,
+    QUARTER, SECOND, WEEK, YEAR,
 };
+use std::convert::TryFrom;
 use std::string::ToString;
+use std::time::Duration;
 
 #[test]
 fn ms_1d() {
@@ -11,11 +21,25 @@ fn ms_1d() {
 }
 
 #[test]
+#[allow(clippy::unnecessary_to_owned)]
 fn ms_1d_string() {
     let value = ms("1d".to_string()).unwrap();
     assert_eq!(value, 86_400_000)
 }
 
+#[test]
+fn ms_1d_cow() {
+    let value = ms(std::borrow::Cow::Borrowed("1d")).unwrap();
+    assert_eq!(value, 86_400_000)
+}
+
+#[test]
+fn ms_1d_string_ref() {
+    let s = "1d".to_string();
+    let value = ms(&s).unwrap();
+    assert_eq!(value, 86_400_000)
+}
+
 #[test]
 fn ms_2d() {
     let value = ms("2 days").unwrap();
@@ -41,24 +65,28 @@ fn ms_neg_ms() {
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn ms_invalid_postfix() {
     let value = ms("100 test").is_err();
     assert_eq!(value, true)
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn ms_invalid_num() {
     let value = ms("test").is_err();
     assert_eq!(value, true)
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn ms_invalid_num_2() {
     let value = ms("1-2").is_err();
     assert_eq!(value, true)
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn ms_invalid_num_3() {
     let value = ms("1..2").is_err();
     assert_eq!(value, true)
@@ -95,6 +123,125 @@ fn ms_macro_neg_ms() {
     assert_eq!(VALUE, -100)
 }
 
+#[test]
+fn ms_macro_compound() {
+    const VALUE: i64 = ms_expr!(i64, 1 h 30 m);
+    assert_eq!(VALUE, 5_400_000)
+}
+
+#[test]
+fn ms_macro_compound_all_units() {
+    const VALUE: i64 = ms_expr!(i64, 1 y 1 w 1 d 1 h 1 m 1 s 1 ms);
+    assert_eq!(VALUE, ms("1 y 1 w 1 d 1 h 1 m 1 s 1 ms").unwrap())
+}
+
+#[test]
+fn ms_macro_compound_neg() {
+    const VALUE: i64 = ms_expr!(i64, -2 days 4 hours);
+    assert_eq!(VALUE, -187_200_000)
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn ms_macro_compound_f64() {
+    const VALUE: f64 = ms_expr!(f64, 1.5 h 0.5 ms);
+    assert_eq!(VALUE, 5_400_000.5)
+}
+
+#[test]
+fn ms_macro_duration() {
+    static VALUE: Duration = ms_expr!(Duration, 2 d 4 h);
+    assert_eq!(VALUE, Duration::from_secs(187_200))
+}
+
+#[test]
+fn ms_macro_duration_path() {
+    const VALUE: std::time::Duration = ms_expr!(std::time::Duration, 100);
+    assert_eq!(VALUE, Duration::from_millis(100))
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn ms_macro_large_literal() {
+    const VALUE: i64 = ms_expr!(i64, 3000000000);
+    const POSTFIX: i64 = ms_expr!(i64, 3000000000 ms);
+    const FLOAT: f64 = ms_expr!(f64, 3000000000 ms);
+    const NANOS: i64 = ms_expr!(i64 as ns, 3000000000 s);
+    assert_eq!((VALUE, POSTFIX), (3_000_000_000, 3_000_000_000));
+    assert_eq!(FLOAT, 3e9);
+    assert_eq!(NANOS, 3_000_000_000_000_000_000)
+}
+
+#[test]
+fn ms_macro_fraction_i64() {
+    const VALUE: i64 = ms_expr!(i64, 1.5 s);
+    assert_eq!(VALUE, 1_500)
+}
+
+#[test]
+fn ms_macro_fraction_rounding() {
+    const HALF: i64 = ms_expr!(i64, 0.5 ms);
+    const NEGATIVE_HALF: i64 = ms_expr!(i64, -0.5);
+    const SMALL: i64 = ms_expr!(i64, 499 us);
+    assert_eq!((HALF, NEGATIVE_HALF, SMALL), (1, -1, 0))
+}
+
+#[test]
+fn ms_macro_fraction_same_as_ms() {
+    const VALUE: i64 = ms_expr!(i64, 1.25 y 0.3 d 2.7 s);
+    assert_eq!(VALUE, ms("1.25 y 0.3 d 2.7 s").unwrap())
+}
+
+#[test]
+fn ms_macro_nanos() {
+    const VALUE: u128 = ms_expr!(u128 as ns, 2 years 1.5 s 3 ns);
+    assert_eq!(VALUE, 63_115_201_500_000_003)
+}
+
+#[test]
+fn ms_macro_micros() {
+    const VALUE: u64 = ms_expr!(u64 as microseconds, 1.5 ms 499 ns);
+    assert_eq!(VALUE, 1_500)
+}
+
+#[test]
+fn ms_macro_seconds() {
+    const VALUE: i32 = ms_expr!(i32 as s, -1 h 30 m);
+    assert_eq!(VALUE, -5_400)
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn ms_macro_f64_micros() {
+    const VALUE: f64 = ms_expr!(f64, 1 ms 500 us);
+    assert_eq!(VALUE, 1.5)
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn ms_macro_float_not_rounded() {
+    const VALUE: f64 = ms_expr!(f64, 0.0000001 ms);
+    const NEGATIVE: f64 = ms_expr!(f64, -0.25 ns);
+    const SINGLE: f32 = ms_expr!(f32, 0.5 us);
+    const SECONDS: f64 = ms_expr!(f64 as s, 1 h 1 ms);
+    assert_eq!(VALUE, 1e-7);
+    assert_eq!(NEGATIVE, -2.5e-7);
+    assert_eq!(SINGLE, 5e-4);
+    assert_eq!(SECONDS, 3_600.001)
+}
+
+#[test]
+fn ms_macro_duration_fraction() {
+    const VALUE: Duration = ms_expr!(Duration, 1.5 h 0.25 ns);
+    assert_eq!(VALUE, Duration::from_secs(5_400))
+}
+
+#[test]
+fn ms_macro_duration_same_as_ns_into_time() {
+    const VALUE: Duration = ms_expr!(core::time::Duration, 2 years 1.0000000005 s);
+    assert_eq!(VALUE, ns_into_time("2 years 1.0000000005 s").unwrap())
+}
+
 #[test]
 fn ms_into_time_1d() {
     let value = ms_into_time("1d").unwrap();
@@ -114,54 +261,56 @@ fn ms_into_time_2_5h() {
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn ms_into_time_neg_ms() {
     let value = ms_into_time("-100").is_err();
     assert_eq!(value, true)
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn ms_into_time_invalid_postfix() {
     let value = ms_into_time("100 test").is_err();
     assert_eq!(value, true)
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn parse_invalid_num() {
-    let value = parse(b"test").is_err();
+    let value = ns("test").is_err();
     assert_eq!(value, true)
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn parse_invalid_num_2() {
-    let value = parse(b"1-2").is_err();
+    let value = ns("1-2").is_err();
     assert_eq!(value, true)
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn parse_invalid_num_3() {
-    let value = parse(b"1..2").is_err();
+    let value = ns("1..2").is_err();
     assert_eq!(value, true)
 }
 
 #[test]
-#[allow(clippy::float_cmp)]
 fn parse_empty_dot() {
-    let value = parse(b"12.").unwrap();
-    assert_eq!(value, 12.)
+    let value = ns("12.ns").unwrap();
+    assert_eq!(value, 12)
 }
 
 #[test]
-#[allow(clippy::float_cmp)]
 fn parse_num() {
-    let value = parse(b"12").unwrap();
-    assert_eq!(value, 12.)
+    let value = ns("12ns").unwrap();
+    assert_eq!(value, 12)
 }
 
 #[test]
-#[allow(clippy::float_cmp)]
 fn parse_dec_num() {
-    let value = parse(b"12.5").unwrap();
-    assert_eq!(value, 12.5)
+    let value = ns("12.5ms").unwrap();
+    assert_eq!(value, 12_500_000)
 }
 
 #[test]
@@ -261,6 +410,7 @@ fn get_duration_by_postfix_year_neg_space() {
 }
 
 #[test]
+#[allow(clippy::unnecessary_cast)]
 fn get_max_possible_duration_milliseconds() {
     let value = get_max_possible_duration(11 as i64).unwrap();
     assert_eq!(value, "11ms")
@@ -307,6 +457,7 @@ fn get_max_possible_duration_weeks_neg() {
     assert_eq!(value, "-7d")
 }
 #[test]
+#[allow(clippy::unnecessary_cast)]
 fn get_max_possible_duration_long_milliseconds() {
     let value = get_max_possible_duration_long(11 as i64).unwrap();
     assert_eq!(value, "11 milliseconds")
@@ -352,3 +503,2872 @@ fn get_max_possible_duration_long_weeks_neg() {
     let value = get_max_possible_duration_long(-WEEK as i64).unwrap();
     assert_eq!(value, "-7 days")
 }
+
+#[test]
+fn get_max_possible_duration_up_to_day() {
+    let value = get_max_possible_duration_up_to(2 * WEEK as i64, Unit::Day).unwrap();
+    assert_eq!(value, get_max_possible_duration(2 * WEEK as i64).unwrap())
+}
+
+#[test]
+fn get_max_possible_duration_up_to_weeks() {
+    let value = get_max_possible_duration_up_to(2 * WEEK as i64, Unit::Year).unwrap();
+    assert_eq!(value, "2w")
+}
+
+#[test]
+fn get_max_possible_duration_up_to_years() {
+    let value = get_max_possible_duration_up_to(YEAR as i64, Unit::Year).unwrap();
+    assert_eq!(value, "1y")
+}
+
+#[test]
+fn get_max_possible_duration_up_to_years_capped() {
+    let value = get_max_possible_duration_up_to(YEAR as i64, Unit::Week).unwrap();
+    assert_eq!(value, "52w")
+}
+
+#[test]
+fn get_max_possible_duration_up_to_hours() {
+    let value = get_max_possible_duration_up_to(2 * DAY as i64, Unit::Hour).unwrap();
+    assert_eq!(value, "48h")
+}
+
+#[test]
+fn get_max_possible_duration_up_to_less_than_millisecond() {
+    let value = get_max_possible_duration_up_to(SECOND as i64, Unit::Nanosecond).unwrap();
+    assert_eq!(value, "1000ms")
+}
+
+#[test]
+fn get_max_possible_duration_up_to_neg() {
+    let value = get_max_possible_duration_up_to(-3 * WEEK as i64, Unit::Year).unwrap();
+    assert_eq!(value, "-3w")
+}
+
+#[test]
+fn get_max_possible_duration_long_up_to_day() {
+    let value = get_max_possible_duration_long_up_to(YEAR as i64, Unit::Day).unwrap();
+    assert_eq!(value, "365 days")
+}
+
+#[test]
+fn get_max_possible_duration_long_up_to_week() {
+    let value = get_max_possible_duration_long_up_to(WEEK as i64, Unit::Year).unwrap();
+    assert_eq!(value, "1 week")
+}
+
+#[test]
+fn get_max_possible_duration_long_up_to_years() {
+    let value = get_max_possible_duration_long_up_to(2 * YEAR as i64, Unit::Year).unwrap();
+    assert_eq!(value, "2 years")
+}
+
+#[test]
+fn get_max_possible_duration_long_up_to_years_neg() {
+    let value = get_max_possible_duration_long_up_to(-YEAR as i64, Unit::Year).unwrap();
+    assert_eq!(value, "-1 year")
+}
+
+#[test]
+fn get_duration_by_postfix_rounded_half_away_from_zero() {
+    let value = get_duration_by_postfix_rounded(-1500, "s", Rounding::HalfAwayFromZero).unwrap();
+    assert_eq!(value, "-2s")
+}
+
+#[test]
+fn get_duration_by_postfix_rounded_half_even() {
+    let value = get_duration_by_postfix_rounded(2500, "s", Rounding::HalfEven).unwrap();
+    assert_eq!(value, "2s")
+}
+
+#[test]
+fn get_duration_by_postfix_rounded_half_even_odd() {
+    let value = get_duration_by_postfix_rounded(-3500, "s", Rounding::HalfEven).unwrap();
+    assert_eq!(value, "-4s")
+}
+
+#[test]
+fn get_duration_by_postfix_rounded_half_even_years() {
+    let value = get_duration_by_postfix_rounded(5 * YEAR as i64 / 2, "y", Rounding::HalfEven);
+    assert_eq!(value.unwrap(), "2y");
+    let value = get_duration_by_postfix_rounded(5 * YEAR as i64 / 2 + 1, "y", Rounding::HalfEven);
+    assert_eq!(value.unwrap(), "3y")
+}
+
+#[test]
+fn get_duration_by_postfix_rounded_floor() {
+    let value = get_duration_by_postfix_rounded(1999, "s", Rounding::Floor).unwrap();
+    assert_eq!(value, "1s")
+}
+
+#[test]
+fn get_duration_by_postfix_rounded_floor_neg() {
+    let value = get_duration_by_postfix_rounded(-1001, "s", Rounding::Floor).unwrap();
+    assert_eq!(value, "-2s")
+}
+
+#[test]
+fn get_duration_by_postfix_rounded_ceil() {
+    let value = get_duration_by_postfix_rounded(1001, "s", Rounding::Ceil).unwrap();
+    assert_eq!(value, "2s")
+}
+
+#[test]
+fn get_duration_by_postfix_rounded_ceil_neg() {
+    let value = get_duration_by_postfix_rounded(-1999, "s", Rounding::Ceil).unwrap();
+    assert_eq!(value, "-1s")
+}
+
+#[test]
+fn get_duration_by_postfix_rounded_truncate() {
+    let value = get_duration_by_postfix_rounded(-1999, "s", Rounding::Truncate).unwrap();
+    assert_eq!(value, "-1s")
+}
+
+#[test]
+fn get_duration_by_postfix_rounded_exact() {
+    let value = get_duration_by_postfix_rounded(i64::MAX, "ms", Rounding::Floor).unwrap();
+    assert_eq!(value, "9223372036854775807ms")
+}
+
+#[test]
+fn get_duration_by_postfix_rounded_year() {
+    let value = get_duration_by_postfix_rounded(YEAR as i64 - 1, "y", Rounding::Floor).unwrap();
+    assert_eq!(value, "0y")
+}
+
+#[test]
+fn get_duration_by_postfix_rounded_microseconds() {
+    let value = get_duration_by_postfix_rounded(3, "us", Rounding::Floor).unwrap();
+    assert_eq!(value, "3000us")
+}
+
+#[test]
+fn get_duration_by_postfix_rounded_overflow() {
+    let value = get_duration_by_postfix_rounded(i64::MAX, "ns", Rounding::Floor);
+    assert_eq!(value.unwrap_err().kind(), ErrorKind::Overflow)
+}
+
+#[test]
+fn get_max_possible_duration_rounded_floor() {
+    let value = get_max_possible_duration_rounded(2 * DAY as i64 - 1, Unit::Day, Rounding::Floor);
+    assert_eq!(value.unwrap(), "1d")
+}
+
+#[test]
+fn get_max_possible_duration_rounded_ceil() {
+    let value = get_max_possible_duration_rounded(WEEK as i64 + 1, Unit::Year, Rounding::Ceil);
+    assert_eq!(value.unwrap(), "2w")
+}
+
+#[test]
+fn get_max_possible_duration_long_rounded_singular() {
+    let value =
+        get_max_possible_duration_long_rounded(HOUR as i64 * 3 / 2, Unit::Day, Rounding::Truncate);
+    assert_eq!(value.unwrap(), "1 hour")
+}
+
+#[test]
+fn get_max_possible_duration_long_rounded_plural() {
+    let value = get_max_possible_duration_long_rounded(HOUR as i64 + 1, Unit::Day, Rounding::Ceil);
+    assert_eq!(value.unwrap(), "2 hours")
+}
+
+#[test]
+fn get_max_possible_duration_long_zero() {
+    let value = get_max_possible_duration_long(0).unwrap();
+    assert_eq!(value, "0 milliseconds")
+}
+
+#[test]
+fn compound_format_rounding_floor() {
+    let value = CompoundFormat::new()
+        .smallest(Unit::Minute)
+        .rounding(Rounding::Floor)
+        .format(DAY as i64 - 1)
+        .unwrap();
+    assert_eq!(value, "23h 59m")
+}
+
+#[test]
+fn compound_format_rounding_floor_neg() {
+    let value = CompoundFormat::new()
+        .smallest(Unit::Minute)
+        .rounding(Rounding::Floor)
+        .format(-HOUR as i64 - 1)
+        .unwrap();
+    assert_eq!(value, "-1h 1m")
+}
+
+#[test]
+fn compound_format_rounding_ceil() {
+    let value = CompoundFormat::new()
+        .max_components(1)
+        .rounding(Rounding::Ceil)
+        .format(HOUR as i64 + 1)
+        .unwrap();
+    assert_eq!(value, "2h")
+}
+
+#[test]
+fn compound_format_rounding_half_even() {
+    let value = CompoundFormat::new()
+        .smallest(Unit::Second)
+        .rounding(Rounding::HalfEven)
+        .format(2500)
+        .unwrap();
+    assert_eq!(value, "2s")
+}
+
+#[test]
+fn get_duration_by_postfix_fractional_max() {
+    let value = get_duration_by_postfix_fractional(5_400_000, "h", Precision::Max(2)).unwrap();
+    assert_eq!(value, "1.5h")
+}
+
+#[test]
+fn get_duration_by_postfix_fractional_max_whole() {
+    let value = get_duration_by_postfix_fractional(7_200_000, "h", Precision::Max(2)).unwrap();
+    assert_eq!(value, "2h")
+}
+
+#[test]
+fn get_duration_by_postfix_fractional_fixed() {
+    let value = get_duration_by_postfix_fractional(7_200_000, "h", Precision::Fixed(2)).unwrap();
+    assert_eq!(value, "2.00h")
+}
+
+#[test]
+fn get_duration_by_postfix_fractional_zero_digits() {
+    let value = get_duration_by_postfix_fractional(5_400_000, "h", Precision::Fixed(0)).unwrap();
+    assert_eq!(value, "2h")
+}
+
+#[test]
+fn get_duration_by_postfix_fractional_rounding() {
+    let value = get_duration_by_postfix_fractional(1_000, "m", Precision::Max(3)).unwrap();
+    assert_eq!(value, "0.017m")
+}
+
+#[test]
+fn get_duration_by_postfix_fractional_neg_less_than_one() {
+    let value = get_duration_by_postfix_fractional(-500, "s", Precision::Max(3)).unwrap();
+    assert_eq!(value, "-0.5s")
+}
+
+#[test]
+fn get_duration_by_postfix_fractional_neg_zero() {
+    let value = get_duration_by_postfix_fractional(-1, "s", Precision::Max(2)).unwrap();
+    assert_eq!(value, "0s")
+}
+
+#[test]
+fn get_duration_by_postfix_fractional_max_digits() {
+    let value = get_duration_by_postfix_fractional(1, "s", Precision::Fixed(20)).unwrap();
+    assert_eq!(value, "0.001000000s")
+}
+
+#[test]
+fn get_duration_by_postfix_fractional_overflow() {
+    let value = get_duration_by_postfix_fractional(i64::MAX, "ns", Precision::Max(2));
+    assert_eq!(value.unwrap_err().kind(), ErrorKind::Overflow)
+}
+
+#[test]
+fn get_duration_by_postfix_fractional_round_trip() {
+    for value in [1, 1_500, 5_400_000, 194_400_000, -90_061_001] {
+        let s = get_duration_by_postfix_fractional(value, "s", Precision::Max(3)).unwrap();
+        assert_eq!(ms(s).unwrap(), value)
+    }
+}
+
+#[test]
+fn get_max_possible_duration_fractional_weeks() {
+    let value =
+        get_max_possible_duration_fractional(WEEK as i64 * 3 / 2, Unit::Year, Precision::Max(2));
+    assert_eq!(value.unwrap(), "1.5w")
+}
+
+#[test]
+fn get_max_possible_duration_long_fractional_plural() {
+    let value =
+        get_max_possible_duration_long_fractional(194_400_000, Unit::Day, Precision::Max(2));
+    assert_eq!(value.unwrap(), "2.25 days")
+}
+
+#[test]
+fn get_max_possible_duration_long_fractional_fixed_one() {
+    let value =
+        get_max_possible_duration_long_fractional(DAY as i64, Unit::Day, Precision::Fixed(1));
+    assert_eq!(value.unwrap(), "1.0 days")
+}
+
+#[test]
+fn get_max_possible_duration_long_fractional_singular_neg() {
+    let value =
+        get_max_possible_duration_long_fractional(-HOUR as i64, Unit::Day, Precision::Max(1));
+    assert_eq!(value.unwrap(), "-1 hour")
+}
+
+#[test]
+fn get_max_possible_duration_long_fractional_round_trip() {
+    let value =
+        get_max_possible_duration_long_fractional(194_400_000, Unit::Day, Precision::Max(2));
+    assert_eq!(ms(value.unwrap()).unwrap(), 194_400_000)
+}
+
+#[test]
+fn fmt_short() {
+    let value = format!("{}", fmt::Short(2 * WEEK as i64));
+    assert_eq!(value, get_max_possible_duration(2 * WEEK as i64).unwrap())
+}
+
+#[test]
+fn fmt_long() {
+    let value = format!("{}", fmt::Long(-HOUR as i64));
+    assert_eq!(value, "-1 hour")
+}
+
+#[test]
+fn fmt_with_postfix() {
+    let value = format!("{}", fmt::WithPostfix::new(DAY as i64, " hrs").unwrap());
+    assert_eq!(value, "24 hrs")
+}
+
+#[test]
+fn fmt_with_postfix_unknown() {
+    let error = fmt::WithPostfix::new(1, "dya").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::UnknownUnit)
+}
+
+#[test]
+fn fmt_with_postfix_overflow() {
+    let error = fmt::WithPostfix::new(i64::MAX, "ns").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Overflow)
+}
+
+#[test]
+fn fmt_format_args() {
+    let value = std::fmt::format(format_args!(
+        "{} of {}",
+        fmt::Short(1_500),
+        fmt::Long(3_000)
+    ));
+    assert_eq!(value, "2s of 3 seconds")
+}
+
+#[test]
+fn fmt_write_duration_by_postfix() {
+    let mut value = std::string::String::from("timeout: ");
+    fmt::write_duration_by_postfix(&mut value, 90_000, "s").unwrap();
+    assert_eq!(value, "timeout: 90s")
+}
+
+#[test]
+fn fmt_write_duration_by_postfix_unknown() {
+    let mut value = std::string::String::new();
+    let error = fmt::write_duration_by_postfix(&mut value, 90_000, "dya").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::UnknownUnit);
+    assert_eq!(value, "")
+}
+
+#[test]
+fn fmt_write_max_possible_duration_long() {
+    let mut value = std::string::String::new();
+    fmt::write_max_possible_duration_long(&mut value, 2 * DAY as i64).unwrap();
+    assert_eq!(value, "2 days")
+}
+
+#[test]
+fn fmt_write_into_fixed_buffer() {
+    struct Buffer([u8; 8], usize);
+    impl std::fmt::Write for Buffer {
+        fn write_str(&mut self, s: &str) -> std::fmt::Result {
+            let end = self.1 + s.len();
+            self.0
+                .get_mut(self.1..end)
+                .ok_or(std::fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.1 = end;
+            Ok(())
+        }
+    }
+    let mut buffer = Buffer([0; 8], 0);
+    fmt::write_max_possible_duration(&mut buffer, -DAY as i64).unwrap();
+    assert_eq!(&buffer.0[..buffer.1], b"-1d");
+    let error = fmt::write_max_possible_duration_long(&mut buffer, 2 * DAY as i64).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Format)
+}
+
+#[test]
+fn get_compound_duration_all_units() {
+    let value = get_compound_duration(90_061_001).unwrap();
+    assert_eq!(value, "1d 1h 1m 1s 1ms")
+}
+
+#[test]
+fn get_compound_duration_skips_zero_units() {
+    let value = get_compound_duration(DAY as i64 + 5 * SECOND as i64).unwrap();
+    assert_eq!(value, "1d 5s")
+}
+
+#[test]
+fn get_compound_duration_zero() {
+    let value = get_compound_duration(0).unwrap();
+    assert_eq!(value, "0ms")
+}
+
+#[test]
+fn get_compound_duration_neg() {
+    let value = get_compound_duration(-90_061_000).unwrap();
+    assert_eq!(value, "-1d 1h 1m 1s")
+}
+
+#[test]
+fn get_compound_duration_min() {
+    let value = get_compound_duration(i64::MIN).unwrap();
+    assert_eq!(ms(value).unwrap(), i64::MIN)
+}
+
+#[test]
+fn get_compound_duration_round_trip() {
+    for value in [1, 999, 59_999, 90_061_001, 1_000_000_000_007, -5_400_500] {
+        let s = get_compound_duration(value).unwrap();
+        assert_eq!(ms(s).unwrap(), value)
+    }
+}
+
+#[test]
+fn compound_format_long() {
+    let value = CompoundFormat::new().long(true).format(90_061_000).unwrap();
+    assert_eq!(value, "1 day 1 hour 1 minute 1 second")
+}
+
+#[test]
+fn compound_format_long_plural() {
+    let value = CompoundFormat::new()
+        .long(true)
+        .format(2 * DAY as i64 + 1500)
+        .unwrap();
+    assert_eq!(value, "2 days 1 second 500 milliseconds")
+}
+
+#[test]
+fn compound_format_long_round_trip() {
+    let value = CompoundFormat::new()
+        .long(true)
+        .format(-93_784_005)
+        .unwrap();
+    assert_eq!(ms(value).unwrap(), -93_784_005)
+}
+
+#[test]
+fn compound_format_locale() {
+    let value = CompoundFormat::new()
+        .long(true)
+        .locale(Locale::Polish)
+        .format(2 * DAY as i64 + 5 * HOUR as i64 + 22 * MINUTE as i64)
+        .unwrap();
+    assert_eq!(value, "2 dni 5 godzin 22 minuty")
+}
+
+#[test]
+fn compound_format_locale_short() {
+    let value = CompoundFormat::new()
+        .locale(Locale::Russian)
+        .format(5_400_000)
+        .unwrap();
+    assert_eq!(value, "1h 30m")
+}
+
+#[test]
+fn get_max_possible_duration_long_localized_english() {
+    for milliseconds in [
+        0,
+        1,
+        999,
+        1000,
+        1499,
+        1500,
+        -1500,
+        86_400_000,
+        i64::MAX,
+        i64::MIN,
+    ] {
+        assert_eq!(
+            get_max_possible_duration_long_localized(milliseconds, Locale::English).unwrap(),
+            get_max_possible_duration_long(milliseconds).unwrap()
+        )
+    }
+}
+
+#[test]
+fn get_max_possible_duration_long_localized_rounded_count() {
+    let value = get_max_possible_duration_long_localized(1_499, Locale::English).unwrap();
+    assert_eq!(value, "1 second");
+    let value = get_max_possible_duration_long_localized(1_500, Locale::English).unwrap();
+    assert_eq!(value, "2 seconds");
+    let value = get_max_possible_duration_long_localized(21_400, Locale::Russian).unwrap();
+    assert_eq!(value, "21 секунда")
+}
+
+#[test]
+fn get_max_possible_duration_long_localized_russian() {
+    let days = |count: i64| {
+        get_max_possible_duration_long_localized(count * DAY as i64, Locale::Russian).unwrap()
+    };
+    assert_eq!(days(1), "1 день");
+    assert_eq!(days(2), "2 дня");
+    assert_eq!(days(5), "5 дней");
+    assert_eq!(days(11), "11 дней");
+    assert_eq!(days(12), "12 дней");
+    assert_eq!(days(21), "21 день");
+    assert_eq!(days(22), "22 дня");
+    assert_eq!(days(111), "111 дней");
+    assert_eq!(days(-101), "-101 день")
+}
+
+#[test]
+fn get_max_possible_duration_long_localized_polish() {
+    let hours = |count: i64| {
+        get_max_possible_duration_long_localized(count * HOUR as i64, Locale::Polish).unwrap()
+    };
+    assert_eq!(hours(1), "1 godzina");
+    assert_eq!(hours(3), "3 godziny");
+    assert_eq!(hours(12), "12 godzin");
+    assert_eq!(hours(21), "21 godzin");
+    assert_eq!(hours(23), "23 godziny")
+}
+
+#[test]
+fn get_max_possible_duration_long_localized_arabic() {
+    let days = |count: i64| {
+        get_max_possible_duration_long_localized(count * DAY as i64, Locale::Arabic).unwrap()
+    };
+    assert_eq!(days(1), "1 يوم");
+    assert_eq!(days(2), "2 يومان");
+    assert_eq!(days(3), "3 أيام");
+    assert_eq!(days(11), "11 يومًا");
+    assert_eq!(days(100), "100 يوم");
+    assert_eq!(days(102), "102 يوم")
+}
+
+#[test]
+fn get_max_possible_duration_long_localized_french() {
+    let value = get_max_possible_duration_long_localized(0, Locale::French).unwrap();
+    assert_eq!(value, "0 milliseconde");
+    let value = get_max_possible_duration_long_localized(2 * DAY as i64, Locale::French).unwrap();
+    assert_eq!(value, "2 jours");
+    let value =
+        get_max_possible_duration_long_localized(1_000_000 * DAY as i64, Locale::French).unwrap();
+    assert_eq!(value, "1000000 de jours")
+}
+
+#[test]
+fn locale_plural_categories() {
+    use PluralCategory::*;
+    let categories = |locale: Locale| {
+        [0, 1, 2, 3, 5, 11, 12, 21, 22, 25, 102, 111, 1_000_000]
+            .iter()
+            .map(|count| locale.plural_category(*count))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        categories(Locale::English),
+        [Other, One, Other, Other, Other, Other, Other, Other, Other, Other, Other, Other, Other]
+    );
+    assert_eq!(
+        categories(Locale::Spanish),
+        [Other, One, Other, Other, Other, Other, Other, Other, Other, Other, Other, Other, Many]
+    );
+    assert_eq!(
+        categories(Locale::French),
+        [One, One, Other, Other, Other, Other, Other, Other, Other, Other, Other, Other, Many]
+    );
+    assert_eq!(
+        categories(Locale::Ukrainian),
+        [Many, One, Few, Few, Many, Many, Many, One, Few, Many, Few, Many, Many]
+    );
+    assert_eq!(
+        categories(Locale::Polish),
+        [Many, One, Few, Few, Many, Many, Many, Many, Few, Many, Few, Many, Many]
+    );
+    assert_eq!(
+        categories(Locale::Arabic),
+        [Zero, One, Two, Few, Few, Many, Many, Many, Many, Many, Other, Many, Other]
+    )
+}
+
+#[test]
+fn locale_every_unit_name() {
+    for locale in Locale::ALL.iter() {
+        for unit in Unit::ALL.iter() {
+            for count in 0..200 {
+                assert!(!locale.unit_name(*unit, count).is_empty())
+            }
+        }
+    }
+}
+
+#[test]
+fn locale_english_unit_names() {
+    for unit in Unit::ALL.iter() {
+        for count in 0..3 {
+            assert_eq!(
+                Locale::English.unit_name(*unit, count),
+                unit.long_name(count)
+            )
+        }
+    }
+}
+
+#[test]
+fn locale_from_tag() {
+    for locale in Locale::ALL.iter() {
+        assert_eq!(Locale::from_tag(locale.tag()), Some(*locale))
+    }
+    assert_eq!(Locale::from_tag("ES-mx"), Some(Locale::Spanish));
+    assert_eq!(Locale::from_tag(""), None);
+    assert_eq!(Locale::from_tag("-en"), None)
+}
+
+#[test]
+fn fmt_localized() {
+    let value = format!("{}", fmt::Localized(3 * MINUTE as i64, Locale::German));
+    assert_eq!(value, "3 Minuten")
+}
+
+#[test]
+fn compound_format_separator() {
+    let value = CompoundFormat::new()
+        .separator("")
+        .format(9_000_500)
+        .unwrap();
+    assert_eq!(value, "2h30m500ms")
+}
+
+#[test]
+fn compound_format_whitespace_separator() {
+    let value = CompoundFormat::new()
+        .separator("\t")
+        .format(9_000_000)
+        .unwrap();
+    assert_eq!(value, "2h\t30m")
+}
+
+#[test]
+fn compound_format_invalid_separator() {
+    for separator in [", ", "  ", "-", "1", "\u{a0}"] {
+        let value = CompoundFormat::new()
+            .separator(separator)
+            .format(9_000_000)
+            .unwrap_err();
+        assert_eq!(value.kind(), ErrorKind::InvalidSyntax, "{:?}", separator)
+    }
+}
+
+/// Checks that the formatted values are parsed back into the values rounded with the rounding mode
+fn check_compound_round_trip(
+    format: CompoundFormat,
+    locale: Locale,
+    exact: bool,
+    rounding: Rounding,
+) {
+    let values = [
+        0,
+        1,
+        -1,
+        499,
+        -500,
+        59_999,
+        90_061_001,
+        -93_784_005,
+        YEAR as i64 - 1,
+        1_000_000_000_007,
+        i64::MAX,
+        i64::MIN,
+    ];
+    for milliseconds in values {
+        let value = match format.format(milliseconds) {
+            Ok(value) => value,
+            Err(error) => {
+                assert_eq!(error.kind(), ErrorKind::Overflow, "{:?}", format);
+                continue;
+            }
+        };
+        let parsed = match locale {
+            Locale::English => ms(&value),
+            _ => Parser::new().locale(locale).ms(&value),
+        };
+        let parsed = parsed.unwrap_or_else(|e| panic!("{:?}: {}", value, e));
+        let rounded = match rounding {
+            _ if exact => parsed == milliseconds,
+            Rounding::Floor => parsed <= milliseconds,
+            Rounding::Ceil => parsed >= milliseconds,
+            Rounding::Truncate => parsed.unsigned_abs() <= milliseconds.unsigned_abs(),
+            _ => parsed == 0 || parsed.signum() == milliseconds.signum(),
+        };
+        assert!(rounded, "{:?} {}: {:?}", format, milliseconds, value)
+    }
+}
+
+#[test]
+fn compound_format_round_trip_options() {
+    let units = [
+        Unit::Millisecond,
+        Unit::Second,
+        Unit::Minute,
+        Unit::Hour,
+        Unit::Day,
+        Unit::Week,
+        Unit::Year,
+    ];
+    let roundings = [
+        Rounding::HalfAwayFromZero,
+        Rounding::HalfEven,
+        Rounding::Floor,
+        Rounding::Ceil,
+        Rounding::Truncate,
+    ];
+    let separators = ["", " ", "\n"];
+    for (i, (largest, smallest)) in units
+        .iter()
+        .flat_map(|largest| units.iter().map(move |smallest| (*largest, *smallest)))
+        .filter(|(largest, smallest)| smallest <= largest)
+        .enumerate()
+    {
+        let format = CompoundFormat::new()
+            .largest(largest)
+            .smallest(smallest)
+            .separator(separators[i % separators.len()]);
+        for max_components in [1, 2, usize::MAX] {
+            let exact = smallest == Unit::Millisecond && max_components == usize::MAX;
+            for rounding in roundings {
+                let format = format.max_components(max_components).rounding(rounding);
+                check_compound_round_trip(format, Locale::English, exact, rounding);
+            }
+        }
+        for locale in Locale::ALL {
+            let format = format.long(true).locale(locale);
+            let exact = smallest == Unit::Millisecond;
+            check_compound_round_trip(format, locale, exact, Rounding::HalfAwayFromZero);
+        }
+    }
+}
+
+#[test]
+fn compound_format_largest() {
+    let value = CompoundFormat::new()
+        .largest(Unit::Hour)
+        .format(90_061_000)
+        .unwrap();
+    assert_eq!(value, "25h 1m 1s")
+}
+
+#[test]
+fn compound_format_largest_year() {
+    let value = CompoundFormat::new()
+        .largest(Unit::Year)
+        .format(YEAR as i64 + 2 * WEEK as i64 + 3 * DAY as i64)
+        .unwrap();
+    assert_eq!(value, "1y 2w 3d")
+}
+
+#[test]
+fn compound_format_smallest() {
+    let value = CompoundFormat::new()
+        .smallest(Unit::Minute)
+        .format(90_091_000)
+        .unwrap();
+    assert_eq!(value, "1d 1h 2m")
+}
+
+#[test]
+fn compound_format_smallest_zero() {
+    let value = CompoundFormat::new()
+        .smallest(Unit::Second)
+        .format(-400)
+        .unwrap();
+    assert_eq!(value, "0s")
+}
+
+#[test]
+fn compound_format_smallest_less_than_millisecond() {
+    let value = CompoundFormat::new()
+        .smallest(Unit::Nanosecond)
+        .format(1)
+        .unwrap();
+    assert_eq!(value, "1ms")
+}
+
+#[test]
+fn compound_format_max_components() {
+    let value = CompoundFormat::new()
+        .max_components(2)
+        .format(90_061_000)
+        .unwrap();
+    assert_eq!(value, "1d 1h")
+}
+
+#[test]
+fn compound_format_max_components_carry() {
+    let value = CompoundFormat::new()
+        .max_components(2)
+        .format(DAY as i64 - 1)
+        .unwrap();
+    assert_eq!(value, "1d")
+}
+
+#[test]
+fn compound_format_max_components_rounding() {
+    let value = CompoundFormat::new()
+        .max_components(1)
+        .format(5_400_000)
+        .unwrap();
+    assert_eq!(value, "2h")
+}
+
+#[test]
+fn compound_format_year_carry() {
+    let value = CompoundFormat::new()
+        .largest(Unit::Year)
+        .smallest(Unit::Day)
+        .format(YEAR as i64 - 1)
+        .unwrap();
+    assert_eq!(value, "52w 1d");
+    assert_eq!(ms(value).unwrap(), 365 * DAY as i64)
+}
+
+#[test]
+fn compound_format_overflow() {
+    let value = CompoundFormat::new()
+        .smallest(Unit::Second)
+        .format(i64::MAX);
+    assert_eq!(value.unwrap_err().kind(), ErrorKind::Overflow)
+}
+
+#[test]
+fn const_ms_const_context() {
+    const VALUE: i64 = const_ms("1.5h");
+    assert_eq!(VALUE, 5_400_000)
+}
+
+#[test]
+fn const_ms_compound() {
+    const VALUE: i64 = const_ms("-2d4h15m");
+    assert_eq!(VALUE, -188_100_000)
+}
+
+#[test]
+fn const_ms_extreme() {
+    const MIN: i64 = const_ms("-9223372036854775808");
+    assert_eq!(MIN, i64::MIN)
+}
+
+#[test]
+#[should_panic(expected = "invalid postfix")]
+fn const_ms_panics_in_runtime() {
+    const_ms(std::hint::black_box("1 dya"));
+}
+
+#[test]
+fn const_ms_same_as_ms() {
+    let values = [
+        "1d",
+        "1.5h",
+        "100",
+        "-3 days",
+        "+1 week",
+        "2 years",
+        "1h 30m",
+        "2d4h15m",
+        "2 days 4 hours",
+        "1m 1s 1ms",
+        "1.5 hours 30 minutes",
+        "0.1s",
+        ".5s",
+        "1.s",
+        "250us",
+        "1ms 500ns",
+        "1 µs",
+        "1μs",
+        "1 y 1 w 1 d 1 h 1 m 1 s 1 ms",
+    ];
+    for value in values {
+        assert_eq!(
+            crate::constant::parse_const_ms(value.as_bytes()),
+            Ok(ms(value).unwrap())
+        )
+    }
+}
+
+#[test]
+fn const_ms_same_errors_as_ms() {
+    let values = [
+        "",
+        "-",
+        "h",
+        "1..2h",
+        "1 dya",
+        "1h 30",
+        "1h 2h",
+        "1ms 1",
+        "1h -30m",
+        "1h+30m",
+        "1h ",
+        "1h  30m",
+        " 1h",
+        "9223372036854775808",
+        "99999999999999999999999y",
+    ];
+    for value in values {
+        assert_eq!(
+            crate::constant::parse_const_ms(value.as_bytes()),
+            Err(ms(value).unwrap_err().kind()),
+            "{}",
+            value
+        )
+    }
+}
+
+#[test]
+fn ms_compound() {
+    let value = ms("1h 30m").unwrap();
+    assert_eq!(value, 5_400_000)
+}
+
+#[test]
+fn ms_compound_without_spaces() {
+    let value = ms("2d4h15m").unwrap();
+    assert_eq!(value, 188_100_000)
+}
+
+#[test]
+fn ms_compound_long() {
+    let value = ms("2 days 4 hours").unwrap();
+    assert_eq!(value, 187_200_000)
+}
+
+#[test]
+fn ms_compound_fractions() {
+    let value = ms("1.5h 30.5s 250ms").unwrap();
+    assert_eq!(value, 5_430_750)
+}
+
+#[test]
+fn ms_compound_neg() {
+    let value = ms("-1h 30m").unwrap();
+    assert_eq!(value, -5_400_000)
+}
+
+#[test]
+fn ms_compound_pos() {
+    let value = ms("+1h30m").unwrap();
+    assert_eq!(value, 5_400_000)
+}
+
+#[test]
+fn ms_compound_mixed_signs() {
+    let value = ms("1h -30m").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ms_compound_mixed_signs_2() {
+    let value = ms("-1h+30m").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ms_compound_repeated_unit() {
+    let value = ms("1h 2h").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ms_compound_repeated_unit_alias() {
+    let value = ms("1h 2 hours").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ms_compound_missing_postfix() {
+    let value = ms("1h 30").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ms_compound_missing_value() {
+    let value = ms("1h m").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ms_compound_invalid_postfix() {
+    let value = ms("1h 30 test").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ms_compound_trailing_space() {
+    let value = ms("1h 30m ").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ms_into_time_compound() {
+    let value = ms_into_time("1h 30m").unwrap();
+    assert_eq!(value.as_millis(), 5_400_000)
+}
+
+#[test]
+fn ms_from_iso_time() {
+    let value = ms_from_iso("PT1H30M").unwrap();
+    assert_eq!(value, 5_400_000)
+}
+
+#[test]
+fn ms_from_iso_date_time() {
+    let value = ms_from_iso("P3DT4H12M0.5S").unwrap();
+    assert_eq!(value, 274_320_500)
+}
+
+#[test]
+fn ms_from_iso_weeks() {
+    let value = ms_from_iso("P2W").unwrap();
+    assert_eq!(value, 2 * WEEK as i64)
+}
+
+#[test]
+fn ms_from_iso_years_months() {
+    let value = ms_from_iso("P1Y6M").unwrap();
+    assert_eq!(value, (1.5 * YEAR) as i64)
+}
+
+#[test]
+fn ms_from_iso_same_as_ms() {
+    let value = ms_from_iso("P2DT4H").unwrap();
+    assert_eq!(value, ms("2 days 4 hours").unwrap())
+}
+
+#[test]
+fn ms_from_iso_comma_fraction() {
+    let value = ms_from_iso("PT1,5S").unwrap();
+    assert_eq!(value, 1_500)
+}
+
+#[test]
+fn ms_from_iso_exact() {
+    let value = ms_from_iso("PT9007199254740993S").unwrap();
+    assert_eq!(value, 9_007_199_254_740_993_000);
+    let value = ms_from_iso("PT9007199254740.993S").unwrap();
+    assert_eq!(value, 9_007_199_254_740_993);
+    let value = ms_from_iso("PT0,0005S").unwrap();
+    assert_eq!(value, 1)
+}
+
+#[test]
+fn ms_from_iso_fraction_without_integer() {
+    let error = ms_from_iso("PT.S").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidNumber);
+    let error = ms_from_iso("PT,5S").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidNumber);
+    assert_eq!(error.span(), Some(2..4))
+}
+
+#[test]
+fn ms_from_iso_neg() {
+    let value = ms_from_iso("-PT6H").unwrap();
+    assert_eq!(value, -6 * HOUR as i64)
+}
+
+#[test]
+fn ms_from_iso_empty() {
+    let value = ms_from_iso("P").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ms_from_iso_empty_time() {
+    let value = ms_from_iso("P1DT").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ms_from_iso_without_p() {
+    let value = ms_from_iso("T1H").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ms_from_iso_wrong_order() {
+    let value = ms_from_iso("PT30M1H").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ms_from_iso_repeated() {
+    let value = ms_from_iso("P1D2D").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ms_from_iso_hours_before_time() {
+    let value = ms_from_iso("P1H").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ms_from_iso_not_last_fraction() {
+    let value = ms_from_iso("PT1.5H30M").is_err();
+    assert!(value)
+}
+
+#[test]
+fn get_iso_duration_zero() {
+    let value = get_iso_duration(0).unwrap();
+    assert_eq!(value, "PT0S")
+}
+
+#[test]
+fn get_iso_duration_milliseconds() {
+    let value = get_iso_duration(250).unwrap();
+    assert_eq!(value, "PT0.25S")
+}
+
+#[test]
+fn get_iso_duration_time() {
+    let value = get_iso_duration(5_400_000).unwrap();
+    assert_eq!(value, "PT1H30M")
+}
+
+#[test]
+fn get_iso_duration_days() {
+    let value = get_iso_duration(2 * WEEK as i64).unwrap();
+    assert_eq!(value, "P14D")
+}
+
+#[test]
+fn get_iso_duration_date_time() {
+    let value = get_iso_duration(274_320_500).unwrap();
+    assert_eq!(value, "P3DT4H12M0.5S")
+}
+
+#[test]
+fn get_iso_duration_neg() {
+    let value = get_iso_duration(-1_500).unwrap();
+    assert_eq!(value, "-PT1.5S")
+}
+
+#[test]
+fn get_iso_duration_min() {
+    let value = get_iso_duration(i64::MIN).unwrap();
+    assert_eq!(value, "-P106751991167DT7H12M55.808S")
+}
+
+#[test]
+fn get_iso_duration_round_trip() {
+    let value = get_iso_duration(90_061_001).unwrap();
+    assert_eq!(ms_from_iso(value).unwrap(), 90_061_001)
+}
+
+#[test]
+fn ns_from_go_compound() {
+    let value = ns_from_go("1h2m3.5s").unwrap();
+    assert_eq!(value, 3_723_500_000_000)
+}
+
+#[test]
+fn ns_from_go_micro() {
+    let value = ns_from_go("350µs").unwrap();
+    assert_eq!(value, 350_000)
+}
+
+#[test]
+fn ns_from_go_neg() {
+    let value = ns_from_go("-1.5h").unwrap();
+    assert_eq!(value, -5_400_000_000_000)
+}
+
+#[test]
+fn ns_from_go_zero() {
+    let value = ns_from_go("0").unwrap();
+    assert_eq!(value, 0)
+}
+
+#[test]
+fn ns_from_go_missing_postfix() {
+    let value = ns_from_go("3").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ns_from_go_spaces() {
+    let value = ns_from_go("1h 30m").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ns_from_go_days() {
+    let value = ns_from_go("1d").is_err();
+    assert!(value)
+}
+
+// Test cases from the Go `time` package.
+#[test]
+fn ns_from_go_table() {
+    let cases: &[(&str, i64)] = &[
+        ("5s", 5_000_000_000),
+        ("-5s", -5_000_000_000),
+        ("+5s", 5_000_000_000),
+        ("-0", 0),
+        ("+0", 0),
+        ("5.0s", 5_000_000_000),
+        ("5.6s", 5_600_000_000),
+        ("5.s", 5_000_000_000),
+        (".5s", 500_000_000),
+        ("1.004s", 1_004_000_000),
+        ("100.00100s", 100_001_000_000),
+        ("10ns", 10),
+        ("11us", 11_000),
+        ("12\u{b5}s", 12_000),
+        ("12\u{3bc}s", 12_000),
+        ("13ms", 13_000_000),
+        ("15m", 900_000_000_000),
+        ("16h", 57_600_000_000_000),
+        ("3h30m", 12_600_000_000_000),
+        ("10.5s4m", 250_500_000_000),
+        ("-2m3.4s", -123_400_000_000),
+        ("1h2m3s4ms5us6ns", 3_723_004_005_006),
+        ("39h9m14.425s", 140_954_425_000_000),
+        ("52763797000ns", 52_763_797_000),
+        ("0.3333333333333333333h", 1_200_000_000_000),
+        ("9007199254740993ns", 9_007_199_254_740_993),
+        ("9223372036854775807ns", i64::MAX),
+        ("9223372036854775.807us", i64::MAX),
+        ("9223372036s854ms775us807ns", i64::MAX),
+        ("-9223372036854775808ns", i64::MIN),
+        ("-9223372036854775.808us", i64::MIN),
+        ("-9223372036s854ms775us808ns", i64::MIN),
+        ("-2562047h47m16.854775808s", i64::MIN),
+        ("0.100000000000000000000h", 360_000_000_000),
+        ("0.830103483285477580700h", 2_988_372_539_827),
+    ];
+    for (s, expected) in cases {
+        assert_eq!(ns_from_go(*s).unwrap(), *expected, "{}", s)
+    }
+}
+
+// Test cases from the Go `time` package.
+#[test]
+fn ns_from_go_errors_table() {
+    let cases = &[
+        "",
+        "3",
+        "-",
+        "s",
+        ".",
+        "-.",
+        ".s",
+        "+.s",
+        "1d",
+        "9223372036854775808ns",
+        "9223372036854775.808us",
+        "9223372036854ms775us808ns",
+        "-9223372036854775809ns",
+    ];
+    for s in cases {
+        assert!(ns_from_go(*s).is_err(), "{}", s)
+    }
+}
+
+#[test]
+fn get_go_duration_compound() {
+    let value = get_go_duration(3_723_500_000_000).unwrap();
+    assert_eq!(value, "1h2m3.5s")
+}
+
+#[test]
+fn get_go_duration_micro() {
+    let value = get_go_duration(350_000).unwrap();
+    assert_eq!(value, "350µs")
+}
+
+#[test]
+fn get_go_duration_neg() {
+    let value = get_go_duration(-5_400_000_000_000).unwrap();
+    assert_eq!(value, "-1h30m0s")
+}
+
+// Test cases from the Go `time` package.
+#[test]
+fn get_go_duration_table() {
+    let cases: &[(&str, i64)] = &[
+        ("0s", 0),
+        ("1ns", 1),
+        ("1.1\u{b5}s", 1_100),
+        ("2.2ms", 2_200_000),
+        ("3.3s", 3_300_000_000),
+        ("4m5s", 245_000_000_000),
+        ("4m5.001s", 245_001_000_000),
+        ("5h6m7.001s", 18_367_001_000_000),
+        ("8m0.000000001s", 480_000_000_001),
+        ("2562047h47m16.854775807s", i64::MAX),
+        ("-2562047h47m16.854775808s", i64::MIN),
+    ];
+    for (expected, ns) in cases {
+        assert_eq!(get_go_duration(*ns).unwrap(), *expected);
+        assert_eq!(ns_from_go(*expected).unwrap(), *ns);
+    }
+}
+
+#[test]
+fn ms_microseconds() {
+    let value = ms("1500us").unwrap();
+    assert_eq!(value, 2)
+}
+
+#[test]
+fn ms_nanoseconds() {
+    let value = ms("500ns").unwrap();
+    assert_eq!(value, 0)
+}
+
+#[test]
+fn ns_1d() {
+    let value = ns("1d").unwrap();
+    assert_eq!(value, 86_400_000_000_000)
+}
+
+#[test]
+fn ns_microseconds() {
+    let value = ns("250us").unwrap();
+    assert_eq!(value, 250_000)
+}
+
+#[test]
+fn ns_microseconds_long() {
+    let value = ns("250 microseconds").unwrap();
+    assert_eq!(value, 250_000)
+}
+
+#[test]
+fn ns_micros() {
+    let value = ns("2 micros").unwrap();
+    assert_eq!(value, 2_000)
+}
+
+#[test]
+fn ns_micro_sign() {
+    let value = ns("250µs").unwrap();
+    assert_eq!(value, 250_000)
+}
+
+#[test]
+fn ns_nanoseconds() {
+    let value = ns("500ns").unwrap();
+    assert_eq!(value, 500)
+}
+
+#[test]
+fn ns_nanos() {
+    let value = ns("500 nanos").unwrap();
+    assert_eq!(value, 500)
+}
+
+#[test]
+fn ns_nanoseconds_long() {
+    let value = ns("1 nanosecond").unwrap();
+    assert_eq!(value, 1)
+}
+
+#[test]
+fn ns_compound() {
+    let value = ns("1ms 250us 500ns").unwrap();
+    assert_eq!(value, 1_250_500)
+}
+
+#[test]
+fn ns_neg() {
+    let value = ns("-1.5us").unwrap();
+    assert_eq!(value, -1_500)
+}
+
+#[test]
+fn ns_invalid_postfix() {
+    let value = ns("1 ps").is_err();
+    assert!(value)
+}
+
+#[test]
+fn get_duration_by_postfix_microseconds() {
+    let value = get_duration_by_postfix(2, " us").unwrap();
+    assert_eq!(value, "2000 us")
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn microsecond_constants() {
+    assert_eq!(MICROSECOND * 1000., 1.);
+    assert_eq!(NANOSECOND * 1_000_000., 1.);
+}
+
+#[test]
+fn ns_into_time_fraction() {
+    let value = ns_into_time("1.0005s").unwrap();
+    assert_eq!(value.as_nanos(), 1_000_500_000)
+}
+
+#[test]
+fn ns_into_time_exact() {
+    let value = ns_into_time("0.1s").unwrap();
+    assert_eq!(value.as_nanos(), 100_000_000)
+}
+
+#[test]
+fn ns_into_time_1d() {
+    let value = ns_into_time("1d").unwrap();
+    assert_eq!(value.as_millis(), 86_400_000)
+}
+
+#[test]
+fn ns_into_time_year_fraction() {
+    let value = ns_into_time("0.000000000000001y").unwrap();
+    assert_eq!(value.as_nanos(), 32)
+}
+
+#[test]
+fn ns_into_time_compound() {
+    let value = ns_into_time("1h 30m 0.000000123s").unwrap();
+    assert_eq!(value.as_nanos(), 5_400_000_000_123)
+}
+
+#[test]
+fn ns_into_time_sub_nanosecond() {
+    let value = ns_into_time("1.6ns").unwrap();
+    assert_eq!(value.as_nanos(), 2)
+}
+
+#[test]
+fn ns_into_time_long_fraction() {
+    let value = ns_into_time("0.333333333333333333333333333h").unwrap();
+    assert_eq!(value.as_nanos(), 1_200_000_000_000)
+}
+
+#[test]
+fn ns_into_time_big() {
+    let value = ns_into_time("100000000000y").unwrap();
+    assert_eq!(value.as_secs(), 3_155_760_000_000_000_000)
+}
+
+#[test]
+fn ns_into_time_out_of_range() {
+    let value = ns_into_time("1000000000000y").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ns_into_time_neg() {
+    let value = ns_into_time("-1ns").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ns_into_time_invalid_num() {
+    let value = ns_into_time("1..2s").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ns_into_time_invalid_postfix() {
+    let value = ns_into_time("100 test").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ns_exact() {
+    let value = ns("0.1s").unwrap();
+    assert_eq!(value, 100_000_000)
+}
+
+#[test]
+fn ns_min() {
+    let value = ns("-9223372036854775808ns").unwrap();
+    assert_eq!(value, i64::MIN)
+}
+
+#[test]
+fn ns_out_of_range() {
+    let value = ns("9223372036854775808ns").is_err();
+    assert!(value)
+}
+
+#[test]
+fn ms_error_empty() {
+    let error = ms("").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Empty)
+}
+
+#[test]
+fn ms_error_only_sign() {
+    let error = ms("-").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Empty)
+}
+
+#[test]
+fn ms_error_invalid_number() {
+    let error = ms("1..2h").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidNumber);
+    assert_eq!(error.span(), Some(0..5))
+}
+
+#[test]
+fn ms_error_missing_number() {
+    let error = ms("1h m").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidNumber);
+    assert_eq!(error.span(), Some(3..3))
+}
+
+#[test]
+fn ms_error_unknown_unit() {
+    let error = ms("1 dya").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::UnknownUnit);
+    assert_eq!(error.span(), Some(2..5));
+    assert_eq!(error.suggestion(), Some("day"))
+}
+
+#[test]
+fn ms_error_unknown_unit_case() {
+    let error = ms("10 Minutes").unwrap_err();
+    assert_eq!(error.suggestion(), Some("minutes"))
+}
+
+#[test]
+fn ms_error_unknown_unit_without_suggestion() {
+    let error = ms("100 test").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::UnknownUnit);
+    assert_eq!(error.suggestion(), None)
+}
+
+#[test]
+fn ms_error_missing_unit() {
+    let error = ms("1h 30").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::MissingUnit);
+    assert_eq!(error.span(), Some(5..5))
+}
+
+#[test]
+fn ms_error_repeated_unit() {
+    let error = ms("1h 2 hours").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::RepeatedUnit);
+    assert_eq!(error.span(), Some(5..10))
+}
+
+#[test]
+fn ms_error_unexpected_sign() {
+    let error = ms("1h -30m").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::UnexpectedSign);
+    assert_eq!(error.span(), Some(3..4))
+}
+
+#[test]
+fn ms_error_trailing_space() {
+    let error = ms("1h ").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidSyntax);
+    assert_eq!(error.span(), Some(2..3))
+}
+
+#[test]
+fn ms_error_display() {
+    let error = ms("1h 30 mnis").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid postfix at 6..10, did you mean `mins`?"
+    )
+}
+
+#[test]
+fn ms_into_time_error_negative() {
+    let error = ms_into_time("-1d").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::NegativeDuration)
+}
+
+#[test]
+fn ns_error_overflow() {
+    let error = ns("9223372036854775808ns").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Overflow)
+}
+
+#[test]
+fn get_duration_by_postfix_error_suggestion() {
+    let error = get_duration_by_postfix(1, " hurs").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::UnknownUnit);
+    assert_eq!(error.suggestion(), Some("hours"))
+}
+
+#[test]
+fn ms_from_iso_error_missing_p() {
+    let error = ms_from_iso("-T1H").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidSyntax);
+    assert_eq!(error.span(), Some(1..2))
+}
+
+#[test]
+fn ms_from_iso_error_unknown_designator() {
+    let error = ms_from_iso("P1X").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::UnknownUnit);
+    assert_eq!(error.span(), Some(2..3))
+}
+
+#[test]
+fn ms_from_iso_error_repeated_designator() {
+    let error = ms_from_iso("P1D2D").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::RepeatedUnit);
+    assert_eq!(error.span(), Some(4..5))
+}
+
+#[test]
+fn ms_from_iso_error_missing_designator() {
+    let error = ms_from_iso("PT1H30").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::MissingUnit);
+    assert_eq!(error.span(), Some(6..6))
+}
+
+#[test]
+fn ns_from_go_error_unknown_unit() {
+    let error = ns_from_go("1h2mn").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::UnknownUnit);
+    assert_eq!(error.span(), Some(3..5));
+    assert_eq!(error.suggestion(), Some("m"))
+}
+
+#[test]
+fn ns_from_go_error_overflow() {
+    let error = ns_from_go("1h9223372036854775807ns").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Overflow);
+    assert_eq!(error.span(), Some(2..23))
+}
+
+#[test]
+fn ms_overflow() {
+    let error = ms("99999999999999999999y").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Overflow);
+    assert_eq!(error.span(), Some(0..21))
+}
+
+#[test]
+fn ms_overflow_compound() {
+    let error = ms("250000000y 10000000000w").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Overflow)
+}
+
+#[test]
+fn ms_overflow_infinite() {
+    let value = "9".repeat(400) + "y";
+    let error = ms(value).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Overflow)
+}
+
+#[test]
+fn ms_overflow_neg() {
+    let error = ms("-99999999999999999999y").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Overflow)
+}
+
+#[test]
+fn ms_not_overflow() {
+    let value = ms("292000000y").unwrap();
+    assert_eq!(value, 9_214_819_200_000_000_000)
+}
+
+#[test]
+fn ms_into_time_overflow() {
+    let error = ms_into_time("99999999999999999999y").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Overflow)
+}
+
+#[test]
+fn ms_from_iso_overflow() {
+    let error = ms_from_iso("P99999999999999999999Y").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Overflow)
+}
+
+#[test]
+fn get_duration_by_postfix_overflow() {
+    let error = get_duration_by_postfix(i64::MAX, "ns").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Overflow)
+}
+
+#[test]
+fn ms_macro_i32() {
+    const VALUE: i32 = ms_expr!(i32, 24 d);
+    assert_eq!(VALUE, 2_073_600_000)
+}
+
+#[test]
+fn ms_macro_u64_static() {
+    static VALUE: u64 = ms_expr!(u64, 2 w);
+    assert_eq!(VALUE, 1_209_600_000)
+}
+
+#[test]
+fn ms_macro_calendar_units() {
+    const VALUE: u64 = ms_expr!(u64, 6 mo);
+    assert_eq!(VALUE, 15_778_800_000);
+    assert_eq!(ms_expr!(i64, 1 quarter), QUARTER as i64);
+    assert_eq!(ms_expr!(i64, 2 decades), 2 * DECADE as i64);
+    assert_eq!(ms_expr!(i64, 1 century), CENTURY as i64)
+}
+
+#[test]
+fn ms_calendar_units() {
+    assert_eq!(ms("1 month").unwrap(), MONTH as i64);
+    assert_eq!(ms("6 months").unwrap(), 15_778_800_000);
+    assert_eq!(ms("1q").unwrap(), 3 * MONTH as i64);
+    assert_eq!(ms("4 quarters").unwrap(), YEAR as i64);
+    assert_eq!(ms("1 decade").unwrap(), 10 * YEAR as i64);
+    assert_eq!(ms("1 century").unwrap(), 100 * YEAR as i64);
+    assert_eq!(ms("2 centuries").unwrap(), 200 * YEAR as i64);
+    assert_eq!(
+        ms("1 mo 2 w").unwrap(),
+        ms("1 month").unwrap() + 2 * WEEK as i64
+    );
+    assert_eq!(ns("1 mo").unwrap(), 2_629_800_000_000_000);
+    assert_eq!(const_ms("-1 q 1 mo"), -4 * MONTH as i64)
+}
+
+#[test]
+fn ms_calendar_units_not_minutes() {
+    assert_eq!(ms("5m").unwrap(), 5 * MINUTE as i64);
+    assert_eq!(ms("5mo").unwrap(), 5 * MONTH as i64);
+    assert_eq!(ms("5mo 5m").unwrap(), 5 * MONTH as i64 + 5 * MINUTE as i64);
+    assert_eq!(
+        ms("1 mo 1 month").unwrap_err().kind(),
+        ErrorKind::RepeatedUnit
+    );
+    assert_eq!(
+        Parser::new().lenient(true).ms("6 Months").unwrap(),
+        6 * MONTH as i64
+    )
+}
+
+#[test]
+fn get_max_possible_duration_not_calendar_units() {
+    assert_eq!(get_max_possible_duration(MONTH as i64).unwrap(), "30d");
+    assert_eq!(
+        get_max_possible_duration_up_to(ms("1 century").unwrap(), Unit::Century).unwrap(),
+        "100y"
+    );
+    assert_eq!(
+        get_max_possible_duration_long_up_to(QUARTER as i64, Unit::Quarter).unwrap(),
+        "13 weeks"
+    );
+    assert_eq!(
+        CompoundFormat::new()
+            .largest(Unit::Century)
+            .format(ms("1 decade 1 mo").unwrap())
+            .unwrap(),
+        "10y 4w 2d 10h 30m"
+    )
+}
+
+#[test]
+fn parser_locale_calendar_units() {
+    let parser = Parser::new().locales(&[Locale::Russian, Locale::German]);
+    assert_eq!(parser.ms("6 месяцев").unwrap(), 6 * MONTH as i64);
+    assert_eq!(parser.ms("1 Jahrhundert").unwrap(), CENTURY as i64);
+    assert_eq!(
+        Parser::new().locale(Locale::French).ms("2 mois").unwrap(),
+        2 * MONTH as i64
+    )
+}
+
+#[test]
+fn parser_lenient_case() {
+    let parser = Parser::new().lenient(true);
+    assert_eq!(parser.ms("10 Minutes").unwrap(), 600_000);
+    assert_eq!(parser.ms("1H").unwrap(), 3_600_000);
+    assert_eq!(parser.ms("2 DAYS 4 Hrs").unwrap(), 187_200_000);
+    assert_eq!(parser.ms("1 MilliSeconds").unwrap(), 1)
+}
+
+#[test]
+fn parser_lenient_whitespaces() {
+    let parser = Parser::new().lenient(true);
+    assert_eq!(parser.ms("5  s").unwrap(), 5_000);
+    assert_eq!(parser.ms("3 days ").unwrap(), 259_200_000);
+    assert_eq!(parser.ms("\t1h \n 30m\t").unwrap(), 5_400_000);
+    assert_eq!(parser.ms("  - 1.5 h").unwrap(), -5_400_000);
+    assert_eq!(parser.ms(" 100 ").unwrap(), 100)
+}
+
+#[test]
+fn parser_lenient_ns() {
+    let parser = Parser::new().lenient(true);
+    assert_eq!(parser.ns("1 MS  500 NS ").unwrap(), 1_000_500);
+    assert_eq!(
+        parser.ns_into_time(" 1.0005 S").unwrap(),
+        Duration::new(1, 500_000)
+    );
+    assert_eq!(
+        parser.ms_into_time("1 Day").unwrap(),
+        Duration::from_secs(86_400)
+    )
+}
+
+#[test]
+fn parser_lenient_errors() {
+    let parser = Parser::new().lenient(true);
+    assert_eq!(parser.ms("   ").unwrap_err().kind(), ErrorKind::Empty);
+    assert_eq!(parser.ms(" - ").unwrap_err().kind(), ErrorKind::Empty);
+    assert_eq!(
+        parser.ms("1 0 m").unwrap_err().kind(),
+        ErrorKind::MissingUnit
+    );
+    assert_eq!(
+        parser.ms("1h 2H").unwrap_err().kind(),
+        ErrorKind::RepeatedUnit
+    );
+    assert_eq!(
+        parser.ms("1h - 30m").unwrap_err().kind(),
+        ErrorKind::UnexpectedSign
+    );
+    assert_eq!(
+        parser.ms("1 MILLISECONDSS").unwrap_err().kind(),
+        ErrorKind::UnknownUnit
+    );
+    assert_eq!(
+        parser.ms_into_time("-1d").unwrap_err().kind(),
+        ErrorKind::NegativeDuration
+    )
+}
+
+#[test]
+fn parser_lenient_error_span() {
+    let error = Parser::new().lenient(true).ms("  1 Dya ").unwrap_err();
+    assert_eq!(error.span(), Some(4..7));
+    assert_eq!(error.suggestion(), Some("day"))
+}
+
+#[test]
+fn parser_strict_same_as_ms() {
+    let values = [
+        "1d",
+        "1h 30m",
+        "-2d4h15m",
+        "10 Minutes",
+        "5  s",
+        "3 days ",
+        "1H",
+        "",
+    ];
+    for value in values {
+        assert_eq!(Parser::new().ms(value), ms(value));
+        assert_eq!(Parser::default().ns(value), ns(value))
+    }
+}
+
+#[test]
+fn parser_locale_unit_names_same_as_ms() {
+    let counts = (0..30).chain([100, 101, 111, 1_000_000]);
+    for count in counts {
+        for unit in Unit::ALL.iter() {
+            let expected = ms(format!("{}{}", count, unit.short_name())).unwrap();
+            for locale in Locale::ALL.iter() {
+                let value = format!("{} {}", count, locale.unit_name(*unit, count));
+                assert_eq!(Parser::new().locale(*locale).ms(&value).unwrap(), expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn parser_locale_compound() {
+    let parser = Parser::new().locale(Locale::Russian);
+    assert_eq!(parser.ms("1 час 30 минут").unwrap(), 5_400_000);
+    assert_eq!(parser.ms("-2 дня 4 ч").unwrap(), ms("-2d 4h").unwrap());
+    assert_eq!(parser.ms("1.5 часа").unwrap(), 5_400_000);
+    assert_eq!(parser.ms("1 ЧАС 5 Мин").unwrap(), 3_900_000);
+    assert_eq!(parser.ms("1h 30 секунд").unwrap(), 3_630_000)
+}
+
+#[test]
+fn parser_locale_two_words() {
+    let parser = Parser::new().locales(&[Locale::Arabic, Locale::French]);
+    assert_eq!(parser.ms("5 ملي ثانية").unwrap(), 5);
+    assert_eq!(parser.ms("2 دقيقتان 3 ملي ثانية").unwrap(), 120_003);
+    assert_eq!(
+        parser.ms("1000000 de jours").unwrap(),
+        ms("1000000d").unwrap()
+    );
+    assert_eq!(
+        parser.ms("5 ملي  ثانية").unwrap_err().kind(),
+        ErrorKind::UnknownUnit
+    );
+    assert_eq!(parser.lenient(true).ms(" 5 ملي  ثانية ").unwrap(), 5)
+}
+
+#[test]
+fn parser_locale_ns() {
+    let parser = Parser::new().locale(Locale::German);
+    assert_eq!(
+        parser.ns("1,5 Sekunden").unwrap_err().kind(),
+        ErrorKind::UnknownUnit
+    );
+    assert_eq!(parser.ns("1.5 Sekunden").unwrap(), 1_500_000_000);
+    assert_eq!(
+        parser.ns_into_time("3 Tagen").unwrap(),
+        Duration::from_secs(3 * 86_400)
+    )
+}
+
+#[test]
+fn parser_locale_errors() {
+    let parser = Parser::new().locale(Locale::Polish);
+    assert_eq!(
+        parser.ms("1 godzina 2 godz").unwrap_err().kind(),
+        ErrorKind::RepeatedUnit
+    );
+    assert_eq!(
+        parser.ms("1 dzien").unwrap_err().kind(),
+        ErrorKind::UnknownUnit
+    );
+    assert_eq!(
+        parser.ms("1 минут").unwrap_err().kind(),
+        ErrorKind::UnknownUnit
+    );
+    let error = parser.ms("1 godzina 5 dya").unwrap_err();
+    assert_eq!(error.span(), Some(12..15));
+    assert_eq!(error.suggestion(), Some("day"))
+}
+
+#[test]
+fn parser_locales_order() {
+    let parser = Parser::new().locales(&[Locale::Ukrainian, Locale::Russian]);
+    assert_eq!(parser.ms("1 год").unwrap(), ms("1y").unwrap());
+    let parser = Parser::new().locale(Locale::Ukrainian);
+    assert_eq!(parser.ms("1 год").unwrap(), ms("1h").unwrap())
+}
+
+#[test]
+fn unit_registry_default_same_as_ms() {
+    let registry = UnitRegistry::default();
+    let values = [
+        "1d",
+        "1h 30m",
+        "-2d4h15m",
+        "100",
+        "1.5µs",
+        "2 μs",
+        "1 dya",
+        "1h 1hour",
+        "10 Minutes",
+        "1e3",
+        "",
+        "100000000000000y",
+    ];
+    for value in values {
+        assert_eq!(registry.ms(value), ms(value));
+        assert_eq!(registry.ns(value), ns(value));
+        assert_eq!(registry.ms_into_time(value), ms_into_time(value));
+        assert_eq!(registry.ns_into_time(value), ns_into_time(value))
+    }
+}
+
+#[test]
+fn unit_registry_default_same_format() {
+    let registry = UnitRegistry::new();
+    let values = [
+        0,
+        1,
+        -999,
+        1000,
+        1499,
+        1500,
+        59_999,
+        5_400_000,
+        -86_400_000,
+        30 * DAY as i64,
+        i64::MAX,
+        i64::MIN,
+    ];
+    for milliseconds in values {
+        assert_eq!(
+            registry.format(milliseconds),
+            get_max_possible_duration(milliseconds)
+        );
+        assert_eq!(
+            registry.format_long(milliseconds),
+            get_max_possible_duration_long(milliseconds)
+        )
+    }
+}
+
+#[test]
+fn unit_registry_custom_units() {
+    let registry = UnitRegistry::new()
+        .add_unit("sprint", "2w")
+        .and_then(|r| r.add_unit("shift", "8h"))
+        .and_then(|r| r.add_unit("tick", "50ms"))
+        .and_then(|r| r.add_alias("fortnight", "sprint"))
+        .and_then(|r| r.add_unit("sprints", "1 sprint"))
+        .unwrap();
+    assert_eq!(registry.ms("1 sprint").unwrap(), 2 * WEEK as i64);
+    assert_eq!(registry.ms("2 sprints").unwrap(), 4 * WEEK as i64);
+    assert_eq!(registry.ms("1 fortnight").unwrap(), 2 * WEEK as i64);
+    assert_eq!(registry.ms("1.5 shift").unwrap(), 12 * HOUR as i64);
+    assert_eq!(registry.ms("-3 tick").unwrap(), -150);
+    assert_eq!(registry.ns("1 tick").unwrap(), 50_000_000);
+    assert_eq!(
+        registry.ms("1 shift 30 m 2 tick").unwrap(),
+        8 * HOUR as i64 + 30 * MINUTE as i64 + 100
+    );
+    assert_eq!(
+        registry.ms("1 sprint 1 fortnight").unwrap_err().kind(),
+        ErrorKind::RepeatedUnit
+    );
+    assert_eq!(registry.format(2 * WEEK as i64).unwrap(), "14d");
+    assert_eq!(ms("1 sprint").unwrap_err().kind(), ErrorKind::UnknownUnit)
+}
+
+#[test]
+fn unit_registry_suggestion() {
+    let registry = UnitRegistry::new().add_unit("sprint", "2w").unwrap();
+    let error = registry.ms("1 sprnit").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::UnknownUnit);
+    assert_eq!(error.span(), Some(2..8));
+    assert_eq!(error.suggestion(), Some("sprint"))
+}
+
+#[test]
+fn unit_registry_remove() {
+    let registry = UnitRegistry::new().remove("m").unwrap();
+    assert_eq!(
+        registry.ms("5 m").unwrap_err().kind(),
+        ErrorKind::UnknownUnit
+    );
+    assert_eq!(registry.ms("5 min").unwrap(), 300_000);
+    assert_eq!(registry.format(-300_000).unwrap(), "-5min");
+    assert_eq!(registry.format_long(60_000).unwrap(), "1 minute");
+
+    let registry = ["minutes", "minute", "mins"]
+        .iter()
+        .try_fold(registry, |r, name| r.remove(name))
+        .unwrap();
+    assert_eq!(registry.format_long(120_000).unwrap(), "2 min");
+
+    let registry = registry.remove("min").unwrap();
+    assert_eq!(registry.format(120_000).unwrap(), "120s");
+    assert_eq!(registry.format_long(120_000).unwrap(), "120 seconds")
+}
+
+#[test]
+fn unit_registry_remove_every_unit() {
+    let registry = Unit::ALL
+        .iter()
+        .filter(|unit| **unit >= Unit::Millisecond && **unit <= Unit::Day)
+        .flat_map(|unit| [unit.short_name(), unit.long_name(1), unit.long_name(2)])
+        .chain([
+            "hrs", "hr", "mins", "min", "secs", "sec", "msecs", "msec", "",
+        ])
+        .try_fold(UnitRegistry::new(), |r, name| r.remove(name))
+        .unwrap();
+    assert_eq!(
+        registry.format(1000).unwrap_err().kind(),
+        ErrorKind::UnknownUnit
+    );
+    assert_eq!(registry.ms("1w").unwrap(), WEEK as i64)
+}
+
+#[test]
+fn unit_registry_replace_unit() {
+    let registry = UnitRegistry::new()
+        .remove("m")
+        .and_then(|r| r.add_unit("m", "30d"))
+        .unwrap();
+    assert_eq!(registry.ms("1m").unwrap(), 30 * DAY as i64);
+    assert_eq!(registry.format(60_000).unwrap(), "1min")
+}
+
+#[test]
+fn unit_registry_errors() {
+    let registry = UnitRegistry::new();
+    let kind = |result: Result<UnitRegistry, crate::Error>| result.unwrap_err().kind();
+    assert_eq!(
+        kind(registry.clone().add_unit("h", "1d")),
+        ErrorKind::RepeatedUnit
+    );
+    assert_eq!(
+        kind(registry.clone().add_unit("two words", "1d")),
+        ErrorKind::InvalidSyntax
+    );
+    assert_eq!(
+        kind(registry.clone().add_unit("x2", "1d")),
+        ErrorKind::InvalidSyntax
+    );
+    assert_eq!(
+        kind(registry.clone().add_unit("zero", "0s")),
+        ErrorKind::InvalidNumber
+    );
+    assert_eq!(
+        kind(registry.clone().add_unit("neg", "-1s")),
+        ErrorKind::NegativeDuration
+    );
+    assert_eq!(
+        kind(registry.clone().add_unit("bad", "1 dya")),
+        ErrorKind::UnknownUnit
+    );
+    assert_eq!(
+        kind(registry.clone().add_alias("bad", "dya")),
+        ErrorKind::UnknownUnit
+    );
+    assert_eq!(kind(registry.remove("dya")), ErrorKind::UnknownUnit)
+}
+
+#[test]
+fn unit_registry_units_limit() {
+    let names = [
+        "a", "b", "c", "e", "f", "g", "i", "j", "k", "l", "n", "o", "p", "r", "t", "u", "v", "x",
+        "z", "aa",
+    ];
+    let definitions = (1..).map(|seconds| format!("{}.5s", seconds));
+    let mut registry = UnitRegistry::new();
+    let mut added = 0;
+    for (name, definition) in names.iter().zip(definitions) {
+        match registry.clone().add_unit(*name, &definition) {
+            Ok(r) => {
+                registry = r;
+                added += 1
+            }
+            Err(e) => {
+                assert_eq!(e.kind(), ErrorKind::TooManyUnits);
+                break;
+            }
+        }
+    }
+    assert_eq!(added, 19);
+    assert_eq!(
+        registry.ms("1a 1b 1c 1w").unwrap(),
+        1500 + 2500 + 3500 + WEEK as i64
+    )
+}
+
+#[test]
+fn unit_registry_units_limit_localized() {
+    let names = [
+        "a", "b", "c", "e", "f", "g", "i", "j", "k", "l", "n", "o", "p", "r", "t", "u", "v", "x",
+        "z",
+    ];
+    let parser = Parser::new().locale(Locale::German);
+    let mut registry = UnitRegistry::new()
+        .parser(parser)
+        .define("month", "30d")
+        .unwrap();
+    let mut value = String::from("1y 1w 1d 1h 1m 1s 1ms 1us 1ns 1mo 1q 1decade 1century");
+    for (seconds, name) in (1..).zip(names) {
+        registry = registry.add_unit(name, &format!("{}.5s", seconds)).unwrap();
+        value.push_str(&format!(" 1{}", name));
+    }
+    assert!(registry.ms(&value).is_ok());
+    value.push_str(" 1 Monat");
+    let error = registry.ms(&value).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::RepeatedUnit)
+}
+
+#[test]
+fn unit_registry_owned_names() {
+    let names = ["sprint", "sprints"].map(std::string::String::from);
+    let [name, alias] = names.clone();
+    let registry = UnitRegistry::new()
+        .add_unit(name, "2w")
+        .unwrap()
+        .add_alias(alias, &names[0])
+        .unwrap();
+    assert_eq!(registry.ms("2 sprints").unwrap(), 2_419_200_000);
+    assert!(registry.contains("sprint"));
+    let error = registry.ms("1 sprnt").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::UnknownUnit);
+    assert!(registry.remove("sprints").unwrap().ms("1 sprint").is_ok())
+}
+
+#[test]
+fn unit_registry_localized_names() {
+    let parser = Parser::new().locale(Locale::German);
+    let registry = UnitRegistry::new()
+        .parser(parser)
+        .define("month", "30d")
+        .unwrap();
+    assert_eq!(registry.ms("1 Monat").unwrap(), 2_592_000_000);
+    let error = registry.ms("1 mo 1 Monat").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::RepeatedUnit);
+    assert_eq!(error.span(), Some(7..12));
+
+    let registry = UnitRegistry::new().parser(parser).remove("m").unwrap();
+    assert_eq!(registry.ms("5 Minuten").unwrap(), 300_000);
+    let registry = ["minutes", "minute", "mins", "min"]
+        .iter()
+        .try_fold(registry, |registry, name| registry.remove(name))
+        .unwrap();
+    let error = registry.ms("5 Minuten").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::UnknownUnit);
+}
+
+#[test]
+fn unit_registry_large_unit_fraction() {
+    let registry = UnitRegistry::new().add_unit("big", "2 century").unwrap();
+    assert_eq!(
+        registry.ns("0.99999999999999999999 big").unwrap(),
+        6_311_520_000_000_000_000
+    );
+    assert_eq!(
+        registry.ns("0.12345678901234567890123 big").unwrap(),
+        779_199_992_987_199_999
+    );
+    assert_eq!(
+        registry.ns("1.5 big").unwrap_err().kind(),
+        ErrorKind::Overflow
+    )
+}
+
+#[test]
+fn unit_registry_exact_nanos() {
+    let registry = UnitRegistry::new().add_unit("x", "1y 1ns").unwrap();
+    assert_eq!(registry.ns("1 x").unwrap(), 31_557_600_000_000_001);
+    assert_eq!(registry.ns("100 x").unwrap(), 3_155_760_000_000_000_100);
+    assert_eq!(registry.ns("1 x 1 y").unwrap(), 63_115_200_000_000_001);
+    assert_eq!(registry.ms("1 x").unwrap(), YEAR as i64)
+}
+
+#[test]
+fn unit_registry_parser() {
+    let registry = UnitRegistry::new()
+        .add_unit("shift", "8h")
+        .unwrap()
+        .parser(Parser::new().lenient(true).locale(Locale::German));
+    assert_eq!(
+        registry.ms(" 2 SHIFT 3 Stunden ").unwrap(),
+        19 * HOUR as i64
+    );
+    assert_eq!(registry.ms("1 Min").unwrap(), 60_000)
+}
+
+#[test]
+fn unit_registry_define() {
+    let registry = UnitRegistry::new().define("mo", "30d").unwrap();
+    assert_eq!(registry.ms("1 month").unwrap(), 30 * DAY as i64);
+    assert_eq!(registry.ms("2 months").unwrap(), 60 * DAY as i64);
+    assert_eq!(registry.ms("1 q").unwrap(), QUARTER as i64);
+    assert_eq!(registry.format(30 * DAY as i64).unwrap(), "30d");
+
+    let registry = UnitRegistry::new().define("year", "365d").unwrap();
+    assert_eq!(registry.ms("1y").unwrap(), 365 * DAY as i64);
+    assert_eq!(registry.ms("1 decade").unwrap(), 10 * YEAR as i64);
+
+    let registry = UnitRegistry::new().define("min", "60s").unwrap();
+    assert_eq!(registry.format_long(60_000).unwrap(), "1 minute");
+
+    let errors = [
+        UnitRegistry::new().define("mnth", "30d"),
+        UnitRegistry::new().define("month", "0d"),
+        UnitRegistry::new().define("month", "-30d"),
+        UnitRegistry::new().define("month", "30 dys"),
+    ];
+    let kinds = errors.map(|e| e.unwrap_err().kind());
+    assert_eq!(
+        kinds,
+        [
+            ErrorKind::UnknownUnit,
+            ErrorKind::InvalidNumber,
+            ErrorKind::NegativeDuration,
+            ErrorKind::UnknownUnit
+        ]
+    )
+}
+
+#[test]
+fn unit_registry_calendar_units_same_as_ms() {
+    let registry = UnitRegistry::new();
+    for value in [
+        "1 mo",
+        "1.5 months",
+        "3q",
+        "1 decade",
+        "0.25 century",
+        "1 mo 1 m",
+    ] {
+        assert_eq!(registry.ms(value).unwrap(), ms(value).unwrap(), "{}", value);
+        assert_eq!(registry.ns(value).unwrap(), ns(value).unwrap(), "{}", value)
+    }
+}
+
+#[test]
+fn human_duration_parse() {
+    let value: HumanDuration = "1h 30m".parse().unwrap();
+    assert_eq!(value.as_millis(), 5_400_000)
+}
+
+#[test]
+fn human_duration_parse_error() {
+    let value = "1 dya".parse::<HumanDuration>().unwrap_err();
+    assert_eq!(value.kind(), ErrorKind::UnknownUnit)
+}
+
+#[test]
+fn human_duration_display() {
+    let value = HumanDuration::from_millis(2 * DAY as i64);
+    assert_eq!(value.to_string(), "2d")
+}
+
+#[test]
+fn human_duration_display_neg() {
+    let value = HumanDuration::from_millis(-90 * SECOND as i64);
+    assert_eq!(value.to_string(), "-1m 30s")
+}
+
+#[test]
+fn human_duration_display_round_trip() {
+    let values = [
+        0,
+        1,
+        -1,
+        5_400_000,
+        90_061_001,
+        -172_800_500,
+        i64::MAX,
+        i64::MIN,
+        i64::MAX - 1,
+        i64::MIN + 1,
+    ];
+    for value in values {
+        let value = HumanDuration::from_millis(value);
+        assert_eq!(
+            value.to_string().parse::<HumanDuration>().unwrap(),
+            value,
+            "{}",
+            value
+        )
+    }
+}
+
+#[test]
+fn human_duration_rounded() {
+    let value = HumanDuration::from_millis(5_400_000);
+    assert_eq!(value.rounded().to_string(), "2h")
+}
+
+#[test]
+fn human_duration_from_i64() {
+    let value = HumanDuration::from(1_000);
+    assert_eq!(i64::from(value), 1_000)
+}
+
+#[test]
+fn human_duration_from_duration() {
+    let value = HumanDuration::try_from(Duration::from_micros(1_500)).unwrap();
+    assert_eq!(value.as_millis(), 1)
+}
+
+#[test]
+fn human_duration_from_duration_overflow() {
+    let value = HumanDuration::try_from(Duration::from_secs(u64::MAX)).unwrap_err();
+    assert_eq!(value.kind(), ErrorKind::Overflow)
+}
+
+#[test]
+fn human_duration_into_duration() {
+    let value = Duration::try_from(HumanDuration::from_millis(1_500)).unwrap();
+    assert_eq!(value, Duration::from_millis(1_500))
+}
+
+#[test]
+fn human_duration_into_duration_neg() {
+    let value = Duration::try_from(HumanDuration::from_millis(-1)).unwrap_err();
+    assert_eq!(value.kind(), ErrorKind::NegativeDuration)
+}
+
+#[test]
+fn human_duration_ordering() {
+    let mut values = [
+        HumanDuration::from_millis(HOUR as i64),
+        HumanDuration::from_millis(-1),
+        HumanDuration::ZERO,
+    ];
+    values.sort();
+    assert_eq!(
+        values,
+        [
+            HumanDuration::from_millis(-1),
+            HumanDuration::ZERO,
+            HumanDuration::from_millis(HOUR as i64)
+        ]
+    )
+}
+
+#[test]
+fn human_duration_checked_add() {
+    let value = HumanDuration::from_millis(1).checked_add(HumanDuration::from_millis(2));
+    assert_eq!(value, Some(HumanDuration::from_millis(3)))
+}
+
+#[test]
+fn human_duration_checked_add_overflow() {
+    let value = HumanDuration::from_millis(i64::MAX).checked_add(HumanDuration::from_millis(1));
+    assert_eq!(value, None)
+}
+
+#[test]
+fn human_duration_checked_sub() {
+    let value = HumanDuration::from_millis(1).checked_sub(HumanDuration::from_millis(2));
+    assert_eq!(value, Some(HumanDuration::from_millis(-1)))
+}
+
+#[test]
+fn human_duration_checked_mul() {
+    let value = HumanDuration::from_millis(i64::MAX).checked_mul(2);
+    assert_eq!(value, None)
+}
+
+#[test]
+fn human_duration_checked_div() {
+    let value = HumanDuration::from_millis(10).checked_div(0);
+    assert_eq!(value, None)
+}
+
+#[test]
+fn human_duration_checked_neg() {
+    let value = HumanDuration::from_millis(i64::MIN).checked_neg();
+    assert_eq!(value, None)
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use serde::{Deserialize, Serialize};
+    use std::time::Duration;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Millis {
+        #[serde(with = "crate::serde::duration")]
+        duration: Duration,
+        #[serde(with = "crate::serde::millis")]
+        millis: i64,
+        #[serde(default, with = "crate::serde::option_duration")]
+        option: Option<Duration>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Short {
+        #[serde(with = "crate::serde::duration::short")]
+        duration: Duration,
+        #[serde(with = "crate::serde::millis::short")]
+        millis: i64,
+        #[serde(default, with = "crate::serde::option_duration::short")]
+        option: Option<Duration>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Long {
+        #[serde(with = "crate::serde::duration::long")]
+        duration: Duration,
+        #[serde(with = "crate::serde::millis::long")]
+        millis: i64,
+        #[serde(default, with = "crate::serde::option_duration::long")]
+        option: Option<Duration>,
+    }
+
+    #[test]
+    fn deserialize_strings() {
+        let value: Millis =
+            serde_json::from_str(r#"{"duration": "1h 30m", "millis": "-2d", "option": "1s"}"#)
+                .unwrap();
+        assert_eq!(
+            value,
+            Millis {
+                duration: Duration::from_secs(5_400),
+                millis: -172_800_000,
+                option: Some(Duration::from_secs(1)),
+            }
+        )
+    }
+
+    #[test]
+    fn deserialize_numbers() {
+        let value: Millis =
+            serde_json::from_str(r#"{"duration": 1500, "millis": -10, "option": 2.4}"#).unwrap();
+        assert_eq!(
+            value,
+            Millis {
+                duration: Duration::from_millis(1_500),
+                millis: -10,
+                option: Some(Duration::from_millis(2)),
+            }
+        )
+    }
+
+    #[test]
+    fn deserialize_none() {
+        let value: Short =
+            serde_json::from_str(r#"{"duration": 0, "millis": 0, "option": null}"#).unwrap();
+        assert_eq!(value.option, None)
+    }
+
+    #[test]
+    fn deserialize_missing() {
+        let value: Long = serde_json::from_str(r#"{"duration": 0, "millis": 0}"#).unwrap();
+        assert_eq!(value.option, None)
+    }
+
+    #[test]
+    fn deserialize_invalid_postfix() {
+        let value = serde_json::from_str::<Millis>(r#"{"duration": "1 dya", "millis": 0}"#)
+            .unwrap_err()
+            .to_string();
+        assert!(value.contains("did you mean `day`?"), "{}", value)
+    }
+
+    #[test]
+    fn deserialize_negative_duration() {
+        let value = serde_json::from_str::<Millis>(r#"{"duration": "-1s", "millis": 0}"#).is_err();
+        assert!(value)
+    }
+
+    #[test]
+    fn deserialize_negative_option_duration() {
+        let value = serde_json::from_str::<Millis>(r#"{"duration": 0, "millis": 0, "option": -1}"#)
+            .is_err();
+        assert!(value)
+    }
+
+    #[test]
+    fn deserialize_overflow() {
+        let value =
+            serde_json::from_str::<Millis>(r#"{"duration": 0, "millis": 18446744073709551615}"#)
+                .is_err();
+        assert!(value)
+    }
+
+    #[test]
+    fn serialize_millis() {
+        let value = serde_json::to_string(&Millis {
+            duration: Duration::from_secs(90),
+            millis: -1,
+            option: None,
+        })
+        .unwrap();
+        assert_eq!(value, r#"{"duration":90000,"millis":-1,"option":null}"#)
+    }
+
+    #[test]
+    fn serialize_short() {
+        let value = serde_json::to_string(&Short {
+            duration: Duration::from_secs(7_200),
+            millis: -1_000,
+            option: Some(Duration::from_millis(5)),
+        })
+        .unwrap();
+        assert_eq!(value, r#"{"duration":"2h","millis":"-1s","option":"5ms"}"#)
+    }
+
+    #[test]
+    fn serialize_long() {
+        let value = serde_json::to_string(&Long {
+            duration: Duration::from_secs(7_200),
+            millis: 60_000,
+            option: Some(Duration::from_secs(86_400)),
+        })
+        .unwrap();
+        assert_eq!(
+            value,
+            r#"{"duration":"2 hours","millis":"1 minute","option":"1 day"}"#
+        )
+    }
+
+    #[test]
+    fn serialize_overflow() {
+        let value = serde_json::to_string(&Millis {
+            duration: Duration::from_secs(u64::MAX),
+            millis: 0,
+            option: None,
+        })
+        .is_err();
+        assert!(value)
+    }
+
+    #[test]
+    fn round_trip_long() {
+        let value = Long {
+            duration: Duration::from_secs(7_200),
+            millis: 60_000,
+            option: None,
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(serde_json::from_str::<Long>(&json).unwrap(), value)
+    }
+
+    #[test]
+    fn serialize_short_compound() {
+        let value = serde_json::to_string(&Short {
+            duration: Duration::from_secs(90),
+            millis: -5_400_500,
+            option: Some(Duration::from_millis(1_500)),
+        })
+        .unwrap();
+        assert_eq!(
+            value,
+            r#"{"duration":"1m 30s","millis":"-1h 30m 500ms","option":"1s 500ms"}"#
+        )
+    }
+
+    #[test]
+    fn deserialize_duration_nanos() {
+        let value: Short = serde_json::from_str(
+            r#"{"duration": "1.0005s", "millis": "1.0005s", "option": "1.5us"}"#,
+        )
+        .unwrap();
+        assert_eq!(value.duration, Duration::new(1, 500_000));
+        assert_eq!(value.millis, 1_001);
+        assert_eq!(value.option, Some(Duration::from_nanos(1_500)))
+    }
+
+    #[test]
+    fn round_trip_short_fraction() {
+        let value = Short {
+            duration: Duration::from_secs(90),
+            millis: -90_061_001,
+            option: Some(Duration::from_millis(1_500)),
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(serde_json::from_str::<Short>(&json).unwrap(), value)
+    }
+
+    #[test]
+    fn round_trip_long_fraction() {
+        let value = Long {
+            duration: Duration::from_millis(5_400_500),
+            millis: 129_600_000,
+            option: Some(Duration::from_secs(90)),
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(serde_json::from_str::<Long>(&json).unwrap(), value)
+    }
+
+    #[test]
+    fn round_trip_bincode_millis() {
+        let value = Millis {
+            duration: Duration::from_millis(1_500),
+            millis: -10,
+            option: Some(Duration::from_secs(2)),
+        };
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bincode::deserialize::<Millis>(&bytes).unwrap(), value)
+    }
+
+    #[test]
+    fn round_trip_bincode_short() {
+        let value = Short {
+            duration: Duration::from_secs(7_290),
+            millis: -1_500,
+            option: None,
+        };
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bincode::deserialize::<Short>(&bytes).unwrap(), value)
+    }
+
+    #[test]
+    fn round_trip_bincode_long() {
+        let value = Long {
+            duration: Duration::from_secs(60),
+            millis: 86_400_000,
+            option: Some(Duration::from_secs(3_600)),
+        };
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bincode::deserialize::<Long>(&bytes).unwrap(), value)
+    }
+}
+
+#[cfg(feature = "macros")]
+mod macros {
+    use crate::{duration, ms, ms_i64, ns_into_time};
+    use std::time::Duration;
+
+    #[test]
+    fn ms_const_context() {
+        const VALUE: u32 = ms!("1h 30m");
+        assert_eq!(VALUE, 5_400_000)
+    }
+
+    #[test]
+    fn ms_negative() {
+        const VALUE: i64 = ms!("-2 days");
+        assert_eq!(VALUE, -172_800_000)
+    }
+
+    #[test]
+    fn ms_extreme() {
+        const MIN: i64 = ms!("-9223372036854775808");
+        assert_eq!(MIN, i64::MIN)
+    }
+
+    #[test]
+    fn ms_calendar_units() {
+        const VALUE: u64 = ms!("6 months");
+        assert_eq!(VALUE, 15_778_800_000);
+        assert_eq!(
+            ms_i64!("1 century 1 q"),
+            crate::ms("1 century 1 q").unwrap()
+        )
+    }
+
+    #[test]
+    fn ms_i64_const_context() {
+        const VALUE: i64 = ms_i64!("-1.5h");
+        assert_eq!(VALUE, -5_400_000)
+    }
+
+    #[test]
+    fn duration_const_context() {
+        const VALUE: Duration = duration!("1.0005s");
+        assert_eq!(VALUE, Duration::new(1, 500_000))
+    }
+
+    #[test]
+    fn duration_zero() {
+        assert_eq!(duration!("-0s"), Duration::from_secs(0))
+    }
+
+    #[test]
+    fn ms_same_as_ms() {
+        let values = [
+            (ms_i64!("1d"), "1d"),
+            (ms_i64!("1.5h"), "1.5h"),
+            (ms_i64!("100"), "100"),
+            (ms_i64!("-3 days"), "-3 days"),
+            (ms_i64!("+1 week"), "+1 week"),
+            (ms_i64!("2 years"), "2 years"),
+            (ms_i64!("2d4h15m"), "2d4h15m"),
+            (ms_i64!("2 days 4 hours"), "2 days 4 hours"),
+            (ms_i64!("1.5 hours 30 minutes"), "1.5 hours 30 minutes"),
+            (ms_i64!(".5s"), ".5s"),
+            (ms_i64!("1ms 500ns"), "1ms 500ns"),
+            (ms_i64!("1 µs"), "1 µs"),
+            (
+                ms_i64!("1 y 1 w 1 d 1 h 1 m 1 s 1 ms"),
+                "1 y 1 w 1 d 1 h 1 m 1 s 1 ms",
+            ),
+        ];
+        for (value, s) in values {
+            assert_eq!(value, crate::ms(s).unwrap())
+        }
+    }
+
+    #[test]
+    fn duration_same_as_ns_into_time() {
+        let values = [
+            (duration!("1d"), "1d"),
+            (duration!("250us"), "250us"),
+            (duration!("1.0000000005s"), "1.0000000005s"),
+            (duration!("2 years 3ns"), "2 years 3ns"),
+            (duration!("1h 30m 15.5s"), "1h 30m 15.5s"),
+        ];
+        for (value, s) in values {
+            assert_eq!(value, ns_into_time(s).unwrap())
+        }
+    }
+}
+
+mod vercel {
+    use crate::compat::vercel;
+    use crate::ErrorKind;
+    use serde_json::Value;
+
+    /// Time strings and their results of the JavaScript `ms` package, generated by `corpus/vercel-ms.js`
+    const CORPUS: &str = include_str!("../corpus/vercel-ms.json");
+
+    fn corpus(name: &str) -> Vec<Value> {
+        let corpus: Value = serde_json::from_str(CORPUS).unwrap();
+        corpus[name].as_array().unwrap().clone()
+    }
+
+    #[test]
+    fn parse_corpus() {
+        for case in corpus("parse") {
+            let input = case["input"].as_str().unwrap();
+            let value = vercel::parse(input);
+            match &case["output"] {
+                Value::String(output) => assert_eq!(
+                    value.map(f64::to_bits),
+                    Ok(output.parse::<f64>().unwrap().to_bits()),
+                    "{:?}",
+                    input
+                ),
+                Value::Null => assert_eq!(
+                    value.unwrap_err().kind(),
+                    ErrorKind::InvalidSyntax,
+                    "{:?}",
+                    input
+                ),
+                _ => assert_eq!(value.unwrap_err().kind(), ErrorKind::Empty, "{:?}", input),
+            }
+        }
+    }
+
+    #[test]
+    fn format_corpus() {
+        for case in corpus("format") {
+            let input = case["input"].as_str().unwrap().parse::<f64>().unwrap();
+            let value = match case["long"].as_bool().unwrap() {
+                true => vercel::format_long(input),
+                false => vercel::format(input),
+            };
+            match &case["output"] {
+                Value::String(output) => assert_eq!(value.as_ref(), Ok(output), "{:?}", case),
+                _ => assert_eq!(
+                    value.unwrap_err().kind(),
+                    ErrorKind::InvalidNumber,
+                    "{:?}",
+                    case
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn parse_negative_zero() {
+        let value = vercel::parse("-0h").unwrap();
+        assert!(value == 0. && value.is_sign_negative())
+    }
+
+    #[test]
+    fn format_rounds_half_up() {
+        assert_eq!(vercel::format(-2_500.).unwrap(), "-2s");
+        assert_eq!(vercel::format(2_500.).unwrap(), "3s")
+    }
+
+    #[test]
+    fn format_exponent() {
+        assert_eq!(vercel::format(1e300).unwrap(), "1.1574074074074075e+292d");
+        assert_eq!(vercel::format(1e-7).unwrap(), "1e-7ms")
+    }
+
+    #[test]
+    fn write_fixed_buffer() {
+        struct Buffer([u8; 4], usize);
+        impl std::fmt::Write for Buffer {
+            fn write_str(&mut self, s: &str) -> std::fmt::Result {
+                let end = self.1 + s.len();
+                self.0
+                    .get_mut(self.1..end)
+                    .ok_or(std::fmt::Error)?
+                    .copy_from_slice(s.as_bytes());
+                self.1 = end;
+                Ok(())
+            }
+        }
+
+        let mut buffer = Buffer([0; 4], 0);
+        vercel::write(&mut buffer, 60_000.).unwrap();
+        assert_eq!(&buffer.0[..buffer.1], b"1m");
+        let error = vercel::write_long(&mut buffer, 60_000.).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Format)
+    }
+}
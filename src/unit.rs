@@ -0,0 +1,237 @@
+use crate::{CENTURY, DAY, DECADE, HOUR, MICROSECOND, MINUTE, MONTH, NANOSECOND, QUARTER, SECOND};
+use crate::{WEEK, YEAR};
+
+/// Time units, which are known by `ms_converter`, from the smallest to the largest one.
+///
+/// ### Usage
+/// ```
+/// use crate::ms_converter::{Unit, HOUR};
+///
+/// assert_eq!(Unit::Hour.as_millis(), HOUR);
+/// assert_eq!(Unit::Hour.short_name(), "h");
+/// assert_eq!(Unit::Hour.long_name(2), "hours");
+/// assert!(Unit::Hour > Unit::Minute);
+/// ```
+///
+/// Months, quarters, decades and centuries have the fixed length like `MONTH`, `QUARTER`, `DECADE`
+/// and `CENTURY` constants. They are parsed, but the formatting never chooses them,
+/// so the formatted time is written in weeks or years instead.
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use crate::ms_converter::{get_max_possible_duration_up_to, ms, Unit, MONTH};
+///
+/// assert_eq!(Unit::Month.as_millis(), MONTH);
+/// assert_eq!(ms("6 months").unwrap(), 6 * MONTH as i64);
+/// assert_eq!(get_max_possible_duration_up_to(MONTH as i64, Unit::Month).unwrap(), "4w");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Unit {
+    Nanosecond,
+    Microsecond,
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+    Decade,
+    Century,
+}
+
+impl Unit {
+    /// Every unit from the smallest to the largest one
+    pub const ALL: [Unit; 13] = [
+        Unit::Nanosecond,
+        Unit::Microsecond,
+        Unit::Millisecond,
+        Unit::Second,
+        Unit::Minute,
+        Unit::Hour,
+        Unit::Day,
+        Unit::Week,
+        Unit::Month,
+        Unit::Quarter,
+        Unit::Year,
+        Unit::Decade,
+        Unit::Century,
+    ];
+
+    /// Units in the order of the rows of `ms_converter_core::UNITS`
+    #[doc(hidden)]
+    pub(crate) const POSTFIXES_ORDER: [Unit; 13] = [
+        Unit::Year,
+        Unit::Week,
+        Unit::Day,
+        Unit::Hour,
+        Unit::Minute,
+        Unit::Second,
+        Unit::Millisecond,
+        Unit::Microsecond,
+        Unit::Nanosecond,
+        Unit::Month,
+        Unit::Quarter,
+        Unit::Decade,
+        Unit::Century,
+    ];
+
+    /// Every unit, which can be chosen by the formatting, from the smallest to the largest one
+    #[doc(hidden)]
+    pub(crate) const FORMATTED: [Unit; 9] = [
+        Unit::Nanosecond,
+        Unit::Microsecond,
+        Unit::Millisecond,
+        Unit::Second,
+        Unit::Minute,
+        Unit::Hour,
+        Unit::Day,
+        Unit::Week,
+        Unit::Year,
+    ];
+
+    /// How many milliseconds in the unit
+    pub const fn as_millis(self) -> f64 {
+        match self {
+            Unit::Nanosecond => NANOSECOND,
+            Unit::Microsecond => MICROSECOND,
+            Unit::Millisecond => 1_f64,
+            Unit::Second => SECOND,
+            Unit::Minute => MINUTE,
+            Unit::Hour => HOUR,
+            Unit::Day => DAY,
+            Unit::Week => WEEK,
+            Unit::Month => MONTH,
+            Unit::Quarter => QUARTER,
+            Unit::Year => YEAR,
+            Unit::Decade => DECADE,
+            Unit::Century => CENTURY,
+        }
+    }
+
+    /// How many nanoseconds in the unit
+    pub const fn as_nanos(self) -> u64 {
+        match self {
+            Unit::Nanosecond => 1,
+            Unit::Microsecond => 1_000,
+            Unit::Millisecond => 1_000_000,
+            Unit::Second => 1_000_000_000,
+            Unit::Minute => 60_000_000_000,
+            Unit::Hour => 3_600_000_000_000,
+            Unit::Day => 86_400_000_000_000,
+            Unit::Week => 604_800_000_000_000,
+            Unit::Month => 2_629_800_000_000_000,
+            Unit::Quarter => 7_889_400_000_000_000,
+            Unit::Year => 31_557_600_000_000_000,
+            Unit::Decade => 315_576_000_000_000_000,
+            Unit::Century => 3_155_760_000_000_000_000,
+        }
+    }
+
+    /// The shortest postfix of the unit, like `h`
+    pub const fn short_name(self) -> &'static str {
+        match self {
+            Unit::Nanosecond => "ns",
+            Unit::Microsecond => "us",
+            Unit::Millisecond => "ms",
+            Unit::Second => "s",
+            Unit::Minute => "m",
+            Unit::Hour => "h",
+            Unit::Day => "d",
+            Unit::Week => "w",
+            Unit::Month => "mo",
+            Unit::Quarter => "q",
+            Unit::Year => "y",
+            Unit::Decade => "decade",
+            Unit::Century => "century",
+        }
+    }
+
+    /// The long postfix of the unit for the count, like `hour` or `hours`
+    pub const fn long_name(self, count: u64) -> &'static str {
+        let one = count == 1;
+        match self {
+            Unit::Nanosecond if one => "nanosecond",
+            Unit::Nanosecond => "nanoseconds",
+            Unit::Microsecond if one => "microsecond",
+            Unit::Microsecond => "microseconds",
+            Unit::Millisecond if one => "millisecond",
+            Unit::Millisecond => "milliseconds",
+            Unit::Second if one => "second",
+            Unit::Second => "seconds",
+            Unit::Minute if one => "minute",
+            Unit::Minute => "minutes",
+            Unit::Hour if one => "hour",
+            Unit::Hour => "hours",
+            Unit::Day if one => "day",
+            Unit::Day => "days",
+            Unit::Week if one => "week",
+            Unit::Week => "weeks",
+            Unit::Month if one => "month",
+            Unit::Month => "months",
+            Unit::Quarter if one => "quarter",
+            Unit::Quarter => "quarters",
+            Unit::Year if one => "year",
+            Unit::Year => "years",
+            Unit::Decade if one => "decade",
+            Unit::Decade => "decades",
+            Unit::Century if one => "century",
+            Unit::Century => "centuries",
+        }
+    }
+
+    /// Finds the unit by the postfix, like `hrs`, `ms` or the empty postfix for milliseconds
+    ///
+    /// ```
+    /// use crate::ms_converter::Unit;
+    ///
+    /// assert_eq!(Unit::from_postfix("hrs"), Some(Unit::Hour));
+    /// assert_eq!(Unit::from_postfix(""), Some(Unit::Millisecond));
+    /// assert_eq!(Unit::from_postfix("dya"), None);
+    /// ```
+    pub const fn from_postfix(postfix: &str) -> Option<Unit> {
+        Unit::from_postfix_bytes(postfix.as_bytes())
+    }
+
+    #[inline(always)]
+    #[doc(hidden)]
+    pub(crate) const fn from_postfix_bytes(postfix: &[u8]) -> Option<Unit> {
+        match ms_converter_core::unit_index(postfix) {
+            Some(index) => Some(Unit::POSTFIXES_ORDER[index]),
+            None => None,
+        }
+    }
+
+    /// Built-in postfixes of the unit from the longest one, like `hours` and `h` for an hour
+    #[inline(always)]
+    #[doc(hidden)]
+    pub(crate) fn postfixes(self) -> &'static [&'static str] {
+        Unit::POSTFIXES_ORDER
+            .iter()
+            .position(|unit| *unit == self)
+            .map_or(&[], |index| ms_converter_core::UNITS[index].0)
+    }
+
+    /// Finds the built-in unit with the nanoseconds count
+    #[inline(always)]
+    #[doc(hidden)]
+    pub(crate) const fn from_nanos(nanos: u64) -> Option<Unit> {
+        let mut i = 0;
+        while i < Unit::ALL.len() {
+            if Unit::ALL[i].as_nanos() == nanos {
+                return Some(Unit::ALL[i]);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// How many whole milliseconds in the unit, units less than a millisecond have zero
+    #[inline(always)]
+    #[doc(hidden)]
+    pub(crate) const fn whole_millis(self) -> u64 {
+        self.as_nanos() / 1_000_000
+    }
+}